@@ -12,12 +12,34 @@ use egui::{
     TopBottomPanel, Vec2, Visuals,
 };
 use sa_core::{
-    sa_export, sa_generate, SAGeneratorOptions, SAOperation, SAProblemInputBuffer, SAValidity,
+    sa_export, sa_from_roman, sa_generate, sa_generate_base, sa_generate_clock,
+    sa_generate_comparisons, sa_generate_equations, sa_generate_for_student, sa_generate_geometry,
+    sa_generate_matrices, sa_generate_money, sa_generate_percent, sa_generate_place_value,
+    sa_generate_polynomials, sa_generate_probability, sa_generate_roman, sa_generate_rounding,
+    sa_generate_scientific, sa_generate_sequences, sa_generate_statistics, sa_generate_systems,
+    sa_generate_word_problems, sa_layout, sa_place_value_name, SAAnswerForm, SABaseInputBuffer,
+    SABaseKind, SABaseOptions, SABlankPosition, SAClockFormat, SAClockInputBuffer, SAClockKind,
+    SAClockOptions, SAComparisonInputBuffer, SAComparisonOptions, SACurrencyLocale,
+    SAEquationOptions, SAEquationSteps, SAExportOptions, SAFigure, SAGeneratorOptions,
+    SAGeometryMeasure, SAGeometryOptions, SALayout, SAMatrixKind, SAMatrixOptions, SAMatrixSize,
+    SAMoneyInputBuffer, SAMoneyKind, SAMoneyOptions, SANoonCrossing, SANumberBase, SAOperation,
+    SAPercentInputBuffer, SAPercentKind, SAPercentOptions, SAPlaceValueInputBuffer,
+    SAPlaceValueKind, SAPlaceValueOptions, SAPolynomialDegree, SAPolynomialKind,
+    SAPolynomialOptions, SAProbabilityExperiment, SAProbabilityOptions, SAProblem,
+    SAProblemInputBuffer, SARomanDirection, SARomanOptions, SARoundingKind, SARoundingOptions,
+    SARoundingRule, SAScientificInputBuffer, SAScientificKind, SAScientificOptions,
+    SASequenceInputBuffer, SASequenceKind, SASequenceOptions, SAShapeKind, SAStatisticMeasure,
+    SAStatisticsInputBuffer, SAStatisticsOptions, SAStudentRecord, SASystemInputBuffer,
+    SASystemOptions, SAValidity,
 };
 
 #[derive(Default)]
 struct SAGui {
     status: String,
+    preview: String,
+
+    buffer_space_columns: String,
+    export: SAExportOptions,
 
     buffer_space_addition: SAProblemInputBuffer,
     buffer_space_subtraction: SAProblemInputBuffer,
@@ -28,6 +50,66 @@ struct SAGui {
     subtraction: SAGeneratorOptions,
     multiplication: SAGeneratorOptions,
     division: SAGeneratorOptions,
+
+    buffer_space_practice: SAProblemInputBuffer,
+    practice: SAGeneratorOptions,
+    practice_student: String,
+    practice_missed: String,
+
+    buffer_space_sequence: SASequenceInputBuffer,
+    sequence: SASequenceOptions,
+
+    buffer_space_word: SAProblemInputBuffer,
+    word: SAGeneratorOptions,
+
+    buffer_space_comparison: SAComparisonInputBuffer,
+    comparison: SAComparisonOptions,
+
+    buffer_space_rounding: SAProblemInputBuffer,
+    rounding: SARoundingOptions,
+
+    buffer_space_place_value: SAPlaceValueInputBuffer,
+    place_value: SAPlaceValueOptions,
+
+    buffer_space_money: SAMoneyInputBuffer,
+    money: SAMoneyOptions,
+
+    buffer_space_clock: SAClockInputBuffer,
+    clock: SAClockOptions,
+
+    buffer_space_percent: SAPercentInputBuffer,
+    percent: SAPercentOptions,
+
+    buffer_space_equation: SAProblemInputBuffer,
+    equation: SAEquationOptions,
+
+    buffer_space_base: SABaseInputBuffer,
+    base: SABaseOptions,
+
+    buffer_space_statistics: SAStatisticsInputBuffer,
+    statistics: SAStatisticsOptions,
+
+    buffer_space_geometry: SAProblemInputBuffer,
+    geometry: SAGeometryOptions,
+
+    buffer_space_roman: SAProblemInputBuffer,
+    buffer_space_roman_check: String,
+    roman: SARomanOptions,
+
+    buffer_space_probability: SAProblemInputBuffer,
+    probability: SAProbabilityOptions,
+
+    buffer_space_polynomial: SAProblemInputBuffer,
+    polynomial: SAPolynomialOptions,
+
+    buffer_space_system: SASystemInputBuffer,
+    system: SASystemOptions,
+
+    buffer_space_matrix: SAProblemInputBuffer,
+    matrix: SAMatrixOptions,
+
+    buffer_space_scientific: SAScientificInputBuffer,
+    scientific: SAScientificOptions,
 }
 
 impl SAGui {
@@ -37,6 +119,14 @@ impl SAGui {
 
         Self {
             status: "Ready".to_owned(),
+            preview: "".to_owned(),
+
+            buffer_space_columns: "".to_owned(),
+            export: SAExportOptions {
+                layout: SALayout::Horizontal,
+                columns: 4i32,
+                worked_steps: false,
+            },
 
             buffer_space_addition: SAProblemInputBuffer::new(),
             buffer_space_subtraction: SAProblemInputBuffer::new(),
@@ -48,28 +138,263 @@ impl SAGui {
                 problems_floor: 2i32,
                 problems_ceiling: 250i32,
                 allow_negatives: false,
+                multiple_choice: false,
                 operation: SAOperation::Addition,
+                figure: SAFigure::None,
             },
             subtraction: SAGeneratorOptions {
                 problems_num: 10i32,
                 problems_floor: 2i32,
                 problems_ceiling: 250i32,
                 allow_negatives: false,
+                multiple_choice: false,
                 operation: SAOperation::Subtraction,
+                figure: SAFigure::None,
             },
             multiplication: SAGeneratorOptions {
                 problems_num: 10i32,
                 problems_floor: 2i32,
                 problems_ceiling: 250i32,
                 allow_negatives: false,
+                multiple_choice: false,
                 operation: SAOperation::Multiplication,
+                figure: SAFigure::None,
             },
             division: SAGeneratorOptions {
                 problems_num: 10i32,
                 problems_floor: 2i32,
                 problems_ceiling: 250i32,
                 allow_negatives: false,
+                multiple_choice: false,
                 operation: SAOperation::Division,
+                figure: SAFigure::None,
+            },
+
+            buffer_space_practice: SAProblemInputBuffer::new(),
+            practice: SAGeneratorOptions {
+                problems_num: 10i32,
+                problems_floor: 2i32,
+                problems_ceiling: 12i32,
+                allow_negatives: false,
+                multiple_choice: false,
+                operation: SAOperation::Multiplication,
+                figure: SAFigure::None,
+            },
+            practice_student: "".to_owned(),
+            practice_missed: "".to_owned(),
+
+            buffer_space_sequence: SASequenceInputBuffer::new(),
+            sequence: SASequenceOptions {
+                problems_num: 10i32,
+                sequence_length: 5i32,
+                blanks: 1i32,
+                blank_position: SABlankPosition::End,
+                start_floor: 1i32,
+                start_ceiling: 20i32,
+                step_floor: 2i32,
+                step_ceiling: 10i32,
+                allow_negatives: false,
+                kind: SASequenceKind::Arithmetic,
+            },
+
+            buffer_space_word: SAProblemInputBuffer::new(),
+            word: SAGeneratorOptions {
+                problems_num: 10i32,
+                problems_floor: 2i32,
+                problems_ceiling: 50i32,
+                allow_negatives: false,
+                multiple_choice: false,
+                operation: SAOperation::Addition,
+                figure: SAFigure::None,
+            },
+
+            buffer_space_comparison: SAComparisonInputBuffer::new(),
+            comparison: SAComparisonOptions {
+                base: SAGeneratorOptions {
+                    problems_num: 10i32,
+                    problems_floor: 0i32,
+                    problems_ceiling: 100i32,
+                    allow_negatives: false,
+                    multiple_choice: false,
+                    operation: SAOperation::Addition,
+                    figure: SAFigure::None,
+                },
+                less_weight: 1i32,
+                greater_weight: 1i32,
+                equal_weight: 1i32,
+                use_expressions: false,
+            },
+
+            buffer_space_rounding: SAProblemInputBuffer::new(),
+            rounding: SARoundingOptions {
+                base: SAGeneratorOptions {
+                    problems_num: 10i32,
+                    problems_floor: 10i32,
+                    problems_ceiling: 9999i32,
+                    allow_negatives: false,
+                    multiple_choice: false,
+                    operation: SAOperation::Addition,
+                    figure: SAFigure::None,
+                },
+                place: 100i32,
+                rule: SARoundingRule::HalfUp,
+                kind: SARoundingKind::Round,
+            },
+
+            buffer_space_place_value: SAPlaceValueInputBuffer::new(),
+            place_value: SAPlaceValueOptions {
+                problems_num: 10i32,
+                digits: 4i32,
+                asked_place: 0i32,
+                kind: SAPlaceValueKind::DigitInPlace,
+            },
+
+            buffer_space_money: SAMoneyInputBuffer::new(),
+            money: SAMoneyOptions {
+                problems_num: 10i32,
+                problems_floor: 25i32,
+                problems_ceiling: 2000i32,
+                kind: SAMoneyKind::MakingChange,
+                locale: SACurrencyLocale::UnitedStates,
+                coins_only: false,
+                round_to_five: false,
+            },
+
+            buffer_space_clock: SAClockInputBuffer::new(),
+            clock: SAClockOptions {
+                problems_num: 10i32,
+                kind: SAClockKind::AddDuration,
+                format: SAClockFormat::TwelveHour,
+                granularity: 5i32,
+                max_duration: 180i32,
+                noon: SANoonCrossing::Allowed,
+            },
+
+            buffer_space_percent: SAPercentInputBuffer::new(),
+            percent: SAPercentOptions {
+                problems_num: 10i32,
+                problems_floor: 10i32,
+                problems_ceiling: 200i32,
+                kind: SAPercentKind::PercentOf,
+                form: SAAnswerForm::Integer,
+            },
+
+            buffer_space_equation: SAProblemInputBuffer::new(),
+            equation: SAEquationOptions {
+                problems_num: 10i32,
+                solution_floor: 0i32,
+                solution_ceiling: 20i32,
+                allow_negatives: false,
+                steps: SAEquationSteps::OneStep,
+                operations: vec![
+                    SAOperation::Addition,
+                    SAOperation::Subtraction,
+                    SAOperation::Multiplication,
+                    SAOperation::Division,
+                ],
+            },
+
+            buffer_space_base: SABaseInputBuffer::new(),
+            base: SABaseOptions {
+                problems_num: 10i32,
+                kind: SABaseKind::ToBase,
+                base: SANumberBase::Binary,
+                bit_width: 8i32,
+                allow_negatives: false,
+            },
+
+            buffer_space_statistics: SAStatisticsInputBuffer::new(),
+            statistics: SAStatisticsOptions {
+                problems_num: 10i32,
+                problems_floor: 1i32,
+                problems_ceiling: 20i32,
+                allow_negatives: false,
+                list_size: 5i32,
+                measure: SAStatisticMeasure::Mean,
+                whole_mean: false,
+            },
+
+            buffer_space_geometry: SAProblemInputBuffer::new(),
+            geometry: SAGeometryOptions {
+                problems_num: 10i32,
+                problems_floor: 2i32,
+                problems_ceiling: 20i32,
+                measure: SAGeometryMeasure::Perimeter,
+                shapes: vec![
+                    SAShapeKind::Rectangle,
+                    SAShapeKind::Square,
+                    SAShapeKind::RightTriangle,
+                    SAShapeKind::LShape,
+                ],
+            },
+
+            buffer_space_roman: SAProblemInputBuffer::new(),
+            buffer_space_roman_check: "".to_owned(),
+            roman: SARomanOptions {
+                problems_num: 10i32,
+                problems_floor: 1i32,
+                problems_ceiling: 3999i32,
+                direction: SARomanDirection::ToRoman,
+            },
+
+            buffer_space_probability: SAProblemInputBuffer::new(),
+            probability: SAProbabilityOptions {
+                problems_num: 10i32,
+                max_items: 10i32,
+                experiments: vec![
+                    SAProbabilityExperiment::DiceSum,
+                    SAProbabilityExperiment::Outfits,
+                    SAProbabilityExperiment::Permutations,
+                    SAProbabilityExperiment::Combinations,
+                ],
+            },
+
+            buffer_space_polynomial: SAProblemInputBuffer::new(),
+            polynomial: SAPolynomialOptions {
+                problems_num: 10i32,
+                root_floor: -9i32,
+                root_ceiling: 9i32,
+                degree: SAPolynomialDegree::Quadratic,
+                kind: SAPolynomialKind::Expand,
+            },
+
+            buffer_space_system: SASystemInputBuffer::new(),
+            system: SASystemOptions {
+                problems_num: 10i32,
+                solution_floor: -10i32,
+                solution_ceiling: 10i32,
+                coefficient_floor: -5i32,
+                coefficient_ceiling: 5i32,
+            },
+
+            buffer_space_matrix: SAProblemInputBuffer::new(),
+            matrix: SAMatrixOptions {
+                problems_num: 10i32,
+                entry_floor: -5i32,
+                entry_ceiling: 5i32,
+                size: SAMatrixSize::TwoByTwo,
+                kinds: vec![
+                    SAMatrixKind::Addition,
+                    SAMatrixKind::Multiplication,
+                    SAMatrixKind::Determinant,
+                    SAMatrixKind::Inverse,
+                ],
+                rational_entries: false,
+            },
+
+            buffer_space_scientific: SAScientificInputBuffer::new(),
+            scientific: SAScientificOptions {
+                problems_num: 10i32,
+                exponent_floor: -6i32,
+                exponent_ceiling: 6i32,
+                significant_figures: 3i32,
+                kinds: vec![
+                    SAScientificKind::ToScientific,
+                    SAScientificKind::FromScientific,
+                    SAScientificKind::Multiplication,
+                    SAScientificKind::Division,
+                    SAScientificKind::SignificantFigures,
+                ],
             },
         }
     }
@@ -208,6 +533,20 @@ impl eframe::App for SAGui {
                     ui.label("Whether we allow negative numbers to be generated (default: false)");
                     ui.checkbox(&mut self.addition.allow_negatives, "Allow Negative Numbers");
 
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.addition.multiple_choice, "Multiple Choice");
+
+                    //Section: Figure
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with a number line figure (default: none)");
+                    ui.horizontal(|ui| {
+                        for figure in [SAFigure::None, SAFigure::NumberLine] {
+                            ui.radio_value(&mut self.addition.figure, figure, figure.as_string());
+                        }
+                    });
+
                     ui.add_space(bottom_spacing);
 
                     //Section: Generate Button
@@ -216,8 +555,9 @@ impl eframe::App for SAGui {
                             "Generating addition problems with parameters, standby...".to_owned();
                         let generated_package = sa_generate(&self.addition);
                         self.status = "Generated! Pushing to file...".to_owned();
+                        self.preview = sa_layout(&generated_package, &self.export, false);
 
-                        match sa_export(generated_package) {
+                        match sa_export(generated_package, &self.export) {
                             Ok((sfn, ifn)) => {
                                 self.status = format!("Success!\nExported to files:\n{sfn}\n{ifn}")
                             }
@@ -320,6 +660,20 @@ impl eframe::App for SAGui {
                         "Allow Negative Numbers",
                     );
 
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.subtraction.multiple_choice, "Multiple Choice");
+
+                    //Section: Figure
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with a number line figure (default: none)");
+                    ui.horizontal(|ui| {
+                        for figure in [SAFigure::None, SAFigure::NumberLine] {
+                            ui.radio_value(&mut self.subtraction.figure, figure, figure.as_string());
+                        }
+                    });
+
                     ui.add_space(bottom_spacing);
 
                     //Section: Generate Button
@@ -328,8 +682,9 @@ impl eframe::App for SAGui {
                             .to_owned();
                         let generated_package = sa_generate(&self.subtraction);
                         self.status = "Generated! Pushing to file...".to_owned();
+                        self.preview = sa_layout(&generated_package, &self.export, false);
 
-                        match sa_export(generated_package) {
+                        match sa_export(generated_package, &self.export) {
                             Ok((sfn, ifn)) => {
                                 self.status = format!("Success!\nExported to files:\n{sfn}\n{ifn}")
                             }
@@ -432,6 +787,20 @@ impl eframe::App for SAGui {
                         "Allow Negative Numbers",
                     );
 
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.multiplication.multiple_choice, "Multiple Choice");
+
+                    //Section: Figure
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with a figure; dot arrays need numbers up to 12 (default: none)");
+                    ui.horizontal(|ui| {
+                        for figure in [SAFigure::None, SAFigure::DotArray, SAFigure::AreaModel] {
+                            ui.radio_value(&mut self.multiplication.figure, figure, figure.as_string());
+                        }
+                    });
+
                     ui.add_space(bottom_spacing);
 
                     //Section: Generate Button
@@ -441,8 +810,9 @@ impl eframe::App for SAGui {
                                 .to_owned();
                         let generated_package = sa_generate(&self.multiplication);
                         self.status = "Generated! Pushing to file...".to_owned();
+                        self.preview = sa_layout(&generated_package, &self.export, false);
 
-                        match sa_export(generated_package) {
+                        match sa_export(generated_package, &self.export) {
                             Ok((sfn, ifn)) => {
                                 self.status = format!("Success!\nExported to files:\n{sfn}\n{ifn}")
                             }
@@ -537,6 +907,11 @@ impl eframe::App for SAGui {
                     ui.label("Whether we allow negative numbers to be generated (default: false)");
                     ui.checkbox(&mut self.division.allow_negatives, "Allow Negative Numbers");
 
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.division.multiple_choice, "Multiple Choice");
+
                     ui.add_space(bottom_spacing);
 
                     //Section: Generate Button
@@ -545,8 +920,9 @@ impl eframe::App for SAGui {
                             "Generating division problems with parameters, standby...".to_owned();
                         let generated_package = sa_generate(&self.division);
                         self.status = "Generated! Pushing to file...".to_owned();
+                        self.preview = sa_layout(&generated_package, &self.export, false);
 
-                        match sa_export(generated_package) {
+                        match sa_export(generated_package, &self.export) {
                             Ok((sfn, ifn)) => {
                                 self.status = format!("Success!\nExported to files:\n{sfn}\n{ifn}")
                             }
@@ -560,20 +936,1574 @@ impl eframe::App for SAGui {
                     ui.add_space(bottom_spacing);
                 });
 
-                ui.add_space(16.0);
+                //---===---===---===---===---===
+                //Segment: Student Practice
+                ui.collapsing("Student Practice (Spaced Repetition)", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
 
-                if ui.add(generate_button("Exit SimAri")).clicked() {
-                    std::process::exit(0);
-                }
-            });
-        });
-    }
-}
+                    //Section: Student Name
+                    ui.add_space(top_spacing);
+                    ui.label("The student this worksheet is for; their progress is saved per name");
+                    ui.add(TextEdit::singleline(&mut self.practice_student));
 
-fn check_input_num<S1: AsRef<str>>(input: S1) -> SAValidity {
-    match input.as_ref().parse::<i32>() {
-        Ok(n) => SAValidity::ValidNumber(n),
-        Err(_) => SAValidity::InvalidNumber,
+                    //Section: Operation
+                    ui.add_space(top_spacing);
+                    ui.label("The operation to practice (default: multiplication)");
+                    operation_radio(ui, &mut self.practice.operation);
+
+                    //Section: Set Amount of Generated Problems
+                    ui.add_space(top_spacing);
+                    ui.label("The number of practice problems to generate (default: 10)");
+                    let practice_resp_num_of = ui.add(TextEdit::singleline(
+                        &mut self.buffer_space_practice.num_of_problems,
+                    ));
+
+                    if practice_resp_num_of.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter))
+                    {
+                        println!(
+                            "Practice Num Of Problems Input: {0}",
+                            &self.buffer_space_practice.num_of_problems
+                        );
+                        match check_input_num(&self.buffer_space_practice.num_of_problems) {
+                            SAValidity::ValidNumber(n) => {
+                                println!("Conversion successful, valid number!");
+                                self.practice.problems_num = n;
+                            }
+                            SAValidity::InvalidNumber => {
+                                println!("Conversion unsuccessful, invalid number!");
+                            }
+                        }
+                    }
+
+                    //Section: Set Generation Floor
+                    ui.add_space(top_spacing);
+                    ui.label("The lowest possible number to generate (default: 2)");
+                    let practice_resp_floor =
+                        ui.add(TextEdit::singleline(&mut self.buffer_space_practice.floor));
+
+                    if practice_resp_floor.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                        println!(
+                            "Practice Floor Input: {0}",
+                            &self.buffer_space_practice.floor
+                        );
+                        match check_input_num(&self.buffer_space_practice.floor) {
+                            SAValidity::ValidNumber(n) => {
+                                println!("Conversion successful, valid number!");
+                                self.practice.problems_floor = n;
+                            }
+                            SAValidity::InvalidNumber => {
+                                println!("Conversion unsuccessful, invalid number!");
+                            }
+                        }
+                    }
+
+                    //Section: Set Generation Ceiling
+                    ui.add_space(top_spacing);
+                    ui.label("The highest possible number to generate (default: 12)");
+                    let practice_resp_ceiling = ui.add(TextEdit::singleline(
+                        &mut self.buffer_space_practice.ceiling,
+                    ));
+
+                    if practice_resp_ceiling.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter))
+                    {
+                        println!(
+                            "Practice Ceiling Input: {0}",
+                            &self.buffer_space_practice.ceiling
+                        );
+                        match check_input_num(&self.buffer_space_practice.ceiling) {
+                            SAValidity::ValidNumber(n) => {
+                                println!("Conversion successful, valid number!");
+                                self.practice.problems_ceiling = n;
+                            }
+                            SAValidity::InvalidNumber => {
+                                println!("Conversion unsuccessful, invalid number!");
+                            }
+                        }
+                    }
+
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.practice.multiple_choice, "Multiple Choice");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Practice Problems")).clicked() {
+                        let today = chrono::offset::Local::now().date_naive();
+
+                        match SAStudentRecord::load(&self.practice_student) {
+                            Ok(mut record) => {
+                                let generated_package =
+                                    sa_generate_for_student(&self.practice, &record, today);
+                                let generated_num = generated_package.len();
+                                let shortfall = (self.practice.problems_num.max(0) as usize)
+                                    .saturating_sub(generated_num);
+                                self.preview = sa_layout(&generated_package, &self.export, false);
+
+                                //The issued worksheet is saved with the record, so Record Results
+                                //works against what was handed out, even after a restart.
+                                record.pending = generated_package.clone();
+
+                                self.status = if generated_package.is_empty() {
+                                    "Error! No practice problems fit those options!".to_owned()
+                                } else if let Err(e) = record.save() {
+                                    format!("Error! Could not save practice record!\nRaw error: {e}")
+                                } else {
+                                    match sa_export(generated_package, &self.export) {
+                                        Ok((sfn, ifn)) if shortfall > 0 => format!(
+                                            "Success, but only {0} of {1} problems fit those options!\nExported to files:\n{sfn}\n{ifn}",
+                                            generated_num,
+                                            self.practice.problems_num
+                                        ),
+                                        result => export_status(result),
+                                    }
+                                };
+                            }
+                            Err(e) => {
+                                self.status =
+                                    format!("Error! Could not load practice record!\nRaw error: {e}")
+                            }
+                        }
+                    }
+
+                    //Section: Record Results
+                    ui.add_space(top_spacing);
+                    ui.label("Problem numbers the student missed on the last worksheet (e.g. 2, 5, 7)");
+                    ui.add(TextEdit::singleline(&mut self.practice_missed));
+
+                    ui.add_space(bottom_spacing);
+
+                    if ui.add(generate_button("Record Results")).clicked() {
+                        let today = chrono::offset::Local::now().date_naive();
+                        let missed: Vec<i32> = self
+                            .practice_missed
+                            .split(|c: char| c == ',' || c.is_whitespace())
+                            .filter_map(|n| match check_input_num(n) {
+                                SAValidity::ValidNumber(n) => Some(n),
+                                SAValidity::InvalidNumber => None,
+                            })
+                            .collect();
+
+                        match SAStudentRecord::load(&self.practice_student) {
+                            Ok(record) if record.pending.is_empty() => {
+                                self.status =
+                                    "Error! Generate a practice worksheet before recording results!"
+                                        .to_owned();
+                            }
+                            Ok(mut record) => {
+                                let recorded = record.record_pending(&missed, today);
+
+                                match record.save() {
+                                    Ok(filename) => {
+                                        self.status = format!(
+                                            "Success!\nRecorded {recorded} problems to practice record:\n{filename}"
+                                        );
+                                        self.practice_missed.clear();
+                                    }
+                                    Err(e) => {
+                                        self.status = format!(
+                                            "Error! Could not save practice record!\nRaw error: {e}"
+                                        )
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                self.status = format!(
+                                    "Error! Could not load practice record!\nRaw error: {e}"
+                                )
+                            }
+                        }
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Sequence Generation
+                ui.collapsing("Generate Sequence Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Sequence Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of sequence to generate (default: arithmetic)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SASequenceKind::Arithmetic,
+                            SASequenceKind::Geometric,
+                            SASequenceKind::SkipCounting,
+                            SASequenceKind::TwoStep,
+                        ] {
+                            ui.radio_value(&mut self.sequence.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of sequence problems to generate (default: 10)",
+                        &mut self.buffer_space_sequence.num_of_problems,
+                        &mut self.sequence.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The number of terms in each sequence (default: 5)",
+                        &mut self.buffer_space_sequence.sequence_length,
+                        &mut self.sequence.sequence_length,
+                    );
+                    number_input(
+                        ui,
+                        "The number of blanks in each sequence (default: 1)",
+                        &mut self.buffer_space_sequence.blanks,
+                        &mut self.sequence.blanks,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible starting term (default: 1)",
+                        &mut self.buffer_space_sequence.start_floor,
+                        &mut self.sequence.start_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible starting term (default: 20)",
+                        &mut self.buffer_space_sequence.start_ceiling,
+                        &mut self.sequence.start_ceiling,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible step or ratio (default: 2)",
+                        &mut self.buffer_space_sequence.step_floor,
+                        &mut self.sequence.step_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible step or ratio (default: 10)",
+                        &mut self.buffer_space_sequence.step_ceiling,
+                        &mut self.sequence.step_ceiling,
+                    );
+
+                    //Section: Blank Position
+                    ui.add_space(top_spacing);
+                    ui.label("Where the blanks are placed (default: end)");
+                    ui.horizontal(|ui| {
+                        ui.radio_value(
+                            &mut self.sequence.blank_position,
+                            SABlankPosition::End,
+                            "End",
+                        );
+                        ui.radio_value(
+                            &mut self.sequence.blank_position,
+                            SABlankPosition::Random,
+                            "Random",
+                        );
+                    });
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow decreasing or negative sequences (default: false)");
+                    ui.checkbox(&mut self.sequence.allow_negatives, "Allow Negative Numbers");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_sequences(&self.sequence);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No sequences fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Word Problem Generation
+                ui.collapsing("Generate Word Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Operation
+                    ui.add_space(top_spacing);
+                    ui.label("The operation the word problems are built on (default: addition)");
+                    operation_radio(ui, &mut self.word.operation);
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of word problems to generate (default: 10)",
+                        &mut self.buffer_space_word.num_of_problems,
+                        &mut self.word.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible number to generate (default: 2)",
+                        &mut self.buffer_space_word.floor,
+                        &mut self.word.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible number to generate (default: 50)",
+                        &mut self.buffer_space_word.ceiling,
+                        &mut self.word.problems_ceiling,
+                    );
+
+                    //Section: Multiple Choice
+                    ui.add_space(top_spacing);
+                    ui.label("Whether each problem comes with answer choices (default: false)");
+                    ui.checkbox(&mut self.word.multiple_choice, "Multiple Choice");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_word_problems(&self.word);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No word problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Comparison Generation
+                ui.collapsing("Generate Comparison Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of comparison problems to generate (default: 10)",
+                        &mut self.buffer_space_comparison.num_of_problems,
+                        &mut self.comparison.base.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible number to generate (default: 0)",
+                        &mut self.buffer_space_comparison.floor,
+                        &mut self.comparison.base.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible number to generate (default: 100)",
+                        &mut self.buffer_space_comparison.ceiling,
+                        &mut self.comparison.base.problems_ceiling,
+                    );
+                    number_input(
+                        ui,
+                        "How often the answer is less-than, relative to the others (default: 1)",
+                        &mut self.buffer_space_comparison.less_weight,
+                        &mut self.comparison.less_weight,
+                    );
+                    number_input(
+                        ui,
+                        "How often the answer is greater-than, relative to the others (default: 1)",
+                        &mut self.buffer_space_comparison.greater_weight,
+                        &mut self.comparison.greater_weight,
+                    );
+                    number_input(
+                        ui,
+                        "How often the answer is equal, relative to the others (default: 1)",
+                        &mut self.buffer_space_comparison.equal_weight,
+                        &mut self.comparison.equal_weight,
+                    );
+
+                    //Section: Expressions
+                    ui.add_space(top_spacing);
+                    ui.label("Whether one side is a small expression (default: false)");
+                    ui.checkbox(&mut self.comparison.use_expressions, "Use Expressions");
+                    operation_radio(ui, &mut self.comparison.base.operation);
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow negative numbers to be generated (default: false)");
+                    ui.checkbox(
+                        &mut self.comparison.base.allow_negatives,
+                        "Allow Negative Numbers",
+                    );
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_comparisons(&self.comparison);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No comparisons fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Rounding Generation
+                ui.collapsing("Generate Rounding & Estimation Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("Whether to round single numbers or estimate an operation (default: rounding)");
+                    ui.horizontal(|ui| {
+                        for kind in [SARoundingKind::Round, SARoundingKind::Estimate] {
+                            ui.radio_value(&mut self.rounding.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of rounding problems to generate (default: 10)",
+                        &mut self.buffer_space_rounding.num_of_problems,
+                        &mut self.rounding.base.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible number to generate (default: 10)",
+                        &mut self.buffer_space_rounding.floor,
+                        &mut self.rounding.base.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible number to generate (default: 9999)",
+                        &mut self.buffer_space_rounding.ceiling,
+                        &mut self.rounding.base.problems_ceiling,
+                    );
+
+                    //Section: Place
+                    ui.add_space(top_spacing);
+                    ui.label("The place to round to (default: hundred)");
+                    ui.horizontal(|ui| {
+                        for place in [10, 100, 1000, 10000] {
+                            ui.radio_value(&mut self.rounding.place, place, sa_place_value_name(place));
+                        }
+                    });
+
+                    //Section: Rule
+                    ui.add_space(top_spacing);
+                    ui.label("The rounding rule (default: half up)");
+                    ui.horizontal(|ui| {
+                        for rule in [
+                            SARoundingRule::HalfUp,
+                            SARoundingRule::HalfEven,
+                            SARoundingRule::Down,
+                            SARoundingRule::Up,
+                        ] {
+                            ui.radio_value(&mut self.rounding.rule, rule, rule.as_string());
+                        }
+                    });
+
+                    //Section: Operation
+                    ui.add_space(top_spacing);
+                    ui.label("The operation to estimate (default: addition)");
+                    operation_radio(ui, &mut self.rounding.base.operation);
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow negative numbers to be generated (default: false)");
+                    ui.checkbox(&mut self.rounding.base.allow_negatives, "Allow Negative Numbers");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_rounding(&self.rounding);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No rounding problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Place Value Generation
+                ui.collapsing("Generate Place Value Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of place value problem (default: digit in place)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SAPlaceValueKind::DigitInPlace,
+                            SAPlaceValueKind::ExpandedForm,
+                            SAPlaceValueKind::StandardForm,
+                        ] {
+                            ui.radio_value(&mut self.place_value.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of place value problems to generate (default: 10)",
+                        &mut self.buffer_space_place_value.num_of_problems,
+                        &mut self.place_value.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The number of digits in each number, up to 9 (default: 4)",
+                        &mut self.buffer_space_place_value.digits,
+                        &mut self.place_value.digits,
+                    );
+
+                    //Section: Asked Place
+                    ui.add_space(top_spacing);
+                    ui.label("The place asked about in digit problems (default: any)");
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut self.place_value.asked_place, 0, "any");
+
+                        for place in [1, 10, 100, 1000, 10000] {
+                            ui.radio_value(
+                                &mut self.place_value.asked_place,
+                                place,
+                                format!("{0}s", sa_place_value_name(place)),
+                            );
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_place_value(&self.place_value);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No place value problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Money Generation
+                ui.collapsing("Generate Money Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of money problem (default: making change)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SAMoneyKind::MakingChange,
+                            SAMoneyKind::Total,
+                            SAMoneyKind::PriceTimesQuantity,
+                        ] {
+                            ui.radio_value(&mut self.money.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Currency
+                    ui.add_space(top_spacing);
+                    ui.label("The currency to write amounts in (default: dollars)");
+                    ui.horizontal(|ui| {
+                        for locale in [
+                            SACurrencyLocale::UnitedStates,
+                            SACurrencyLocale::UnitedKingdom,
+                            SACurrencyLocale::Euro,
+                        ] {
+                            ui.radio_value(&mut self.money.locale, locale, locale.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of money problems to generate (default: 10)",
+                        &mut self.buffer_space_money.num_of_problems,
+                        &mut self.money.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible price, in cents (default: 25)",
+                        &mut self.buffer_space_money.floor,
+                        &mut self.money.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible price, in cents (default: 2000)",
+                        &mut self.buffer_space_money.ceiling,
+                        &mut self.money.problems_ceiling,
+                    );
+
+                    //Section: Amount Options
+                    ui.add_space(top_spacing);
+                    ui.label("Whether prices stay under 1.00, so coins alone can pay (default: false)");
+                    ui.checkbox(&mut self.money.coins_only, "Coins Only");
+                    ui.add_space(top_spacing);
+                    ui.label("Whether prices are rounded to 5 cents (default: false)");
+                    ui.checkbox(&mut self.money.round_to_five, "Round to 5 Cents");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_money(&self.money);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No money problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Clock Generation
+                ui.collapsing("Generate Clock & Elapsed Time Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of clock problem (default: time after)");
+                    ui.horizontal(|ui| {
+                        for kind in [SAClockKind::AddDuration, SAClockKind::Elapsed] {
+                            ui.radio_value(&mut self.clock.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Format
+                    ui.add_space(top_spacing);
+                    ui.label("How times are written (default: 12 hour)");
+                    ui.horizontal(|ui| {
+                        for format in [SAClockFormat::TwelveHour, SAClockFormat::TwentyFourHour] {
+                            ui.radio_value(&mut self.clock.format, format, format.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of clock problems to generate (default: 10)",
+                        &mut self.buffer_space_clock.num_of_problems,
+                        &mut self.clock.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The longest possible duration, in minutes (default: 180)",
+                        &mut self.buffer_space_clock.max_duration,
+                        &mut self.clock.max_duration,
+                    );
+
+                    //Section: Granularity
+                    ui.add_space(top_spacing);
+                    ui.label("The minutes every time and duration is a multiple of (default: 5)");
+                    ui.horizontal(|ui| {
+                        for granularity in [1, 5, 15] {
+                            ui.radio_value(
+                                &mut self.clock.granularity,
+                                granularity,
+                                format!("{granularity} min"),
+                            );
+                        }
+                    });
+
+                    //Section: Noon Crossing
+                    ui.add_space(top_spacing);
+                    ui.label("Whether problems pass 12:00 noon (default: allowed)");
+                    ui.horizontal(|ui| {
+                        for noon in [
+                            SANoonCrossing::Never,
+                            SANoonCrossing::Allowed,
+                            SANoonCrossing::Always,
+                        ] {
+                            ui.radio_value(&mut self.clock.noon, noon, noon.as_string());
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_clock(&self.clock);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No clock problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Percent Generation
+                ui.collapsing("Generate Percent & Proportion Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of percent or proportion problem (default: percent of)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SAPercentKind::PercentOf,
+                            SAPercentKind::WhatPercent,
+                            SAPercentKind::Proportion,
+                        ] {
+                            ui.radio_value(&mut self.percent.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Answer Form
+                    ui.add_space(top_spacing);
+                    ui.label("Which answers are allowed (default: integer)");
+                    ui.horizontal(|ui| {
+                        for form in [SAAnswerForm::Integer, SAAnswerForm::TerminatingDecimal] {
+                            ui.radio_value(&mut self.percent.form, form, form.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of percent problems to generate (default: 10)",
+                        &mut self.buffer_space_percent.num_of_problems,
+                        &mut self.percent.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible whole amount (default: 10)",
+                        &mut self.buffer_space_percent.floor,
+                        &mut self.percent.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible whole amount (default: 200)",
+                        &mut self.buffer_space_percent.ceiling,
+                        &mut self.percent.problems_ceiling,
+                    );
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_percent(&self.percent);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No percent problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Equation Generation
+                ui.collapsing("Generate Linear Equations", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Steps
+                    ui.add_space(top_spacing);
+                    ui.label("How many steps it takes to solve each equation (default: one step)");
+                    ui.horizontal(|ui| {
+                        for steps in [SAEquationSteps::OneStep, SAEquationSteps::TwoStep] {
+                            ui.radio_value(&mut self.equation.steps, steps, steps.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of equations to generate (default: 10)",
+                        &mut self.buffer_space_equation.num_of_problems,
+                        &mut self.equation.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible solution (default: 0)",
+                        &mut self.buffer_space_equation.floor,
+                        &mut self.equation.solution_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible solution (default: 20)",
+                        &mut self.buffer_space_equation.ceiling,
+                        &mut self.equation.solution_ceiling,
+                    );
+
+                    //Section: Operations
+                    ui.add_space(top_spacing);
+                    ui.label("The operations that may appear (default: all)");
+                    ui.horizontal(|ui| {
+                        for operation in [
+                            SAOperation::Addition,
+                            SAOperation::Subtraction,
+                            SAOperation::Multiplication,
+                            SAOperation::Division,
+                        ] {
+                            let mut enabled = self.equation.operations.contains(&operation);
+
+                            if ui.checkbox(&mut enabled, operation.as_string()).changed() {
+                                if enabled {
+                                    self.equation.operations.push(operation);
+                                } else {
+                                    self.equation.operations.retain(|o| *o != operation);
+                                }
+                            }
+                        }
+                    });
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow negative numbers to be generated (default: false)");
+                    ui.checkbox(&mut self.equation.allow_negatives, "Allow Negative Numbers");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_equations(&self.equation);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No equations fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Base Generation
+                ui.collapsing("Generate Number Base Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("The kind of base problem (default: decimal to base)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SABaseKind::ToBase,
+                            SABaseKind::FromBase,
+                            SABaseKind::BinaryAddition,
+                        ] {
+                            ui.radio_value(&mut self.base.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Base
+                    ui.add_space(top_spacing);
+                    ui.label("The base to convert to or from (default: binary)");
+                    ui.horizontal(|ui| {
+                        for base in [
+                            SANumberBase::Binary,
+                            SANumberBase::Octal,
+                            SANumberBase::Hexadecimal,
+                        ] {
+                            ui.radio_value(&mut self.base.base, base, base.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of base problems to generate (default: 10)",
+                        &mut self.buffer_space_base.num_of_problems,
+                        &mut self.base.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The bit width of every value, from 2 to 32 (default: 8)",
+                        &mut self.buffer_space_base.bit_width,
+                        &mut self.base.bit_width,
+                    );
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow negative numbers, in two's complement (default: false)");
+                    ui.checkbox(&mut self.base.allow_negatives, "Allow Negative Numbers");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_base(&self.base);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No base problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Statistics Generation
+                ui.collapsing("Generate Statistics Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Measure
+                    ui.add_space(top_spacing);
+                    ui.label("The measure to find (default: mean)");
+                    ui.horizontal(|ui| {
+                        for measure in [
+                            SAStatisticMeasure::Mean,
+                            SAStatisticMeasure::Median,
+                            SAStatisticMeasure::Mode,
+                            SAStatisticMeasure::Range,
+                        ] {
+                            ui.radio_value(&mut self.statistics.measure, measure, measure.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of data sets to generate (default: 10)",
+                        &mut self.buffer_space_statistics.num_of_problems,
+                        &mut self.statistics.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The number of values in each data set, up to 30 (default: 5)",
+                        &mut self.buffer_space_statistics.list_size,
+                        &mut self.statistics.list_size,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible value (default: 1)",
+                        &mut self.buffer_space_statistics.floor,
+                        &mut self.statistics.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible value (default: 20)",
+                        &mut self.buffer_space_statistics.ceiling,
+                        &mut self.statistics.problems_ceiling,
+                    );
+
+                    //Section: Whole Mean
+                    ui.add_space(top_spacing);
+                    ui.label("Whether every data set has a whole-number mean (default: false)");
+                    ui.checkbox(&mut self.statistics.whole_mean, "Whole-Number Mean");
+
+                    //Section: Allow Negatives
+                    ui.add_space(top_spacing);
+                    ui.label("Whether we allow negative numbers to be generated (default: false)");
+                    ui.checkbox(&mut self.statistics.allow_negatives, "Allow Negative Numbers");
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_statistics(&self.statistics);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No data sets fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Geometry Generation
+                ui.collapsing("Generate Perimeter & Area Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Measure
+                    ui.add_space(top_spacing);
+                    ui.label("The measure to find (default: perimeter)");
+                    ui.horizontal(|ui| {
+                        for measure in [SAGeometryMeasure::Perimeter, SAGeometryMeasure::Area] {
+                            ui.radio_value(&mut self.geometry.measure, measure, measure.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of figures to generate (default: 10)",
+                        &mut self.buffer_space_geometry.num_of_problems,
+                        &mut self.geometry.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The shortest possible side, in cm (default: 2)",
+                        &mut self.buffer_space_geometry.floor,
+                        &mut self.geometry.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The longest possible side, in cm (default: 20)",
+                        &mut self.buffer_space_geometry.ceiling,
+                        &mut self.geometry.problems_ceiling,
+                    );
+
+                    //Section: Shapes
+                    ui.add_space(top_spacing);
+                    ui.label("The figures that may appear (default: all)");
+                    ui.horizontal(|ui| {
+                        for shape in [
+                            SAShapeKind::Rectangle,
+                            SAShapeKind::Square,
+                            SAShapeKind::RightTriangle,
+                            SAShapeKind::LShape,
+                        ] {
+                            let mut enabled = self.geometry.shapes.contains(&shape);
+
+                            if ui.checkbox(&mut enabled, shape.as_string()).changed() {
+                                if enabled {
+                                    self.geometry.shapes.push(shape);
+                                } else {
+                                    self.geometry.shapes.retain(|s| *s != shape);
+                                }
+                            }
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_geometry(&self.geometry);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No figures fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Roman Numeral Generation
+                ui.collapsing("Generate Roman Numeral Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Direction
+                    ui.add_space(top_spacing);
+                    ui.label("Which way to convert (default: to Roman)");
+                    ui.horizontal(|ui| {
+                        for direction in [SARomanDirection::ToRoman, SARomanDirection::FromRoman] {
+                            ui.radio_value(&mut self.roman.direction, direction, direction.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of Roman numeral problems to generate (default: 10)",
+                        &mut self.buffer_space_roman.num_of_problems,
+                        &mut self.roman.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible number, at least 1 (default: 1)",
+                        &mut self.buffer_space_roman.floor,
+                        &mut self.roman.problems_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible number, at most 3999 (default: 3999)",
+                        &mut self.buffer_space_roman.ceiling,
+                        &mut self.roman.problems_ceiling,
+                    );
+
+                    //Section: Numeral Check
+                    ui.add_space(top_spacing);
+                    ui.label("Check a Roman numeral, e.g. a student's answer (press enter)");
+                    let resp = ui.add(TextEdit::singleline(&mut self.buffer_space_roman_check));
+
+                    if resp.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                        let numeral = self.buffer_space_roman_check.trim().to_uppercase();
+
+                        self.status = match sa_from_roman(&numeral) {
+                            Some(value) => format!("{numeral} = {value}"),
+                            None => format!("Error! {numeral} is not a valid Roman numeral!"),
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_roman(&self.roman);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No Roman numeral problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Probability Generation
+                ui.collapsing("Generate Probability & Counting Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of probability problems to generate (default: 10)",
+                        &mut self.buffer_space_probability.num_of_problems,
+                        &mut self.probability.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The largest group size in counting problems, up to 20 (default: 10)",
+                        &mut self.buffer_space_probability.ceiling,
+                        &mut self.probability.max_items,
+                    );
+
+                    //Section: Experiments
+                    ui.add_space(top_spacing);
+                    ui.label("The experiments that may appear (default: all)");
+                    ui.horizontal(|ui| {
+                        for experiment in [
+                            SAProbabilityExperiment::DiceSum,
+                            SAProbabilityExperiment::Outfits,
+                            SAProbabilityExperiment::Permutations,
+                            SAProbabilityExperiment::Combinations,
+                        ] {
+                            let mut enabled = self.probability.experiments.contains(&experiment);
+
+                            if ui.checkbox(&mut enabled, experiment.as_string()).changed() {
+                                if enabled {
+                                    self.probability.experiments.push(experiment);
+                                } else {
+                                    self.probability.experiments.retain(|e| *e != experiment);
+                                }
+                            }
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_probability(&self.probability);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No probability problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Polynomial Generation
+                ui.collapsing("Generate Polynomial Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Kind
+                    ui.add_space(top_spacing);
+                    ui.label("Whether to expand the factors or factor the polynomial (default: expand)");
+                    ui.horizontal(|ui| {
+                        for kind in [SAPolynomialKind::Expand, SAPolynomialKind::Factor] {
+                            ui.radio_value(&mut self.polynomial.kind, kind, kind.as_string());
+                        }
+                    });
+
+                    //Section: Degree
+                    ui.add_space(top_spacing);
+                    ui.label("How many linear factors each polynomial has (default: quadratic)");
+                    ui.horizontal(|ui| {
+                        for degree in [SAPolynomialDegree::Quadratic, SAPolynomialDegree::Cubic] {
+                            ui.radio_value(&mut self.polynomial.degree, degree, degree.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of polynomials to generate (default: 10)",
+                        &mut self.buffer_space_polynomial.num_of_problems,
+                        &mut self.polynomial.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible root (default: -9)",
+                        &mut self.buffer_space_polynomial.floor,
+                        &mut self.polynomial.root_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible root (default: 9)",
+                        &mut self.buffer_space_polynomial.ceiling,
+                        &mut self.polynomial.root_ceiling,
+                    );
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_polynomials(&self.polynomial);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No polynomials fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: System Generation
+                ui.collapsing("Generate Systems of Linear Equations", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Numeric Options
+                    ui.add_space(top_spacing);
+                    number_input(
+                        ui,
+                        "The number of systems to generate (default: 10)",
+                        &mut self.buffer_space_system.num_of_problems,
+                        &mut self.system.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible value of x or y (default: -10)",
+                        &mut self.buffer_space_system.solution_floor,
+                        &mut self.system.solution_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible value of x or y (default: 10)",
+                        &mut self.buffer_space_system.solution_ceiling,
+                        &mut self.system.solution_ceiling,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible coefficient (default: -5)",
+                        &mut self.buffer_space_system.coefficient_floor,
+                        &mut self.system.coefficient_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible coefficient (default: 5)",
+                        &mut self.buffer_space_system.coefficient_ceiling,
+                        &mut self.system.coefficient_ceiling,
+                    );
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_systems(&self.system);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No systems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Matrix Generation
+                ui.collapsing("Generate Matrix Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Size
+                    ui.add_space(top_spacing);
+                    ui.label("The size of each matrix (default: 2x2)");
+                    ui.horizontal(|ui| {
+                        for size in [SAMatrixSize::TwoByTwo, SAMatrixSize::ThreeByThree] {
+                            ui.radio_value(&mut self.matrix.size, size, size.as_string());
+                        }
+                    });
+
+                    //Section: Numeric Options
+                    number_input(
+                        ui,
+                        "The number of matrix problems to generate (default: 10)",
+                        &mut self.buffer_space_matrix.num_of_problems,
+                        &mut self.matrix.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible entry (default: -5)",
+                        &mut self.buffer_space_matrix.floor,
+                        &mut self.matrix.entry_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible entry (default: 5)",
+                        &mut self.buffer_space_matrix.ceiling,
+                        &mut self.matrix.entry_ceiling,
+                    );
+
+                    //Section: Rational Entries
+                    ui.add_space(top_spacing);
+                    ui.label("Whether some entries are halves, thirds or quarters (default: false)");
+                    ui.checkbox(&mut self.matrix.rational_entries, "Rational Entries");
+
+                    //Section: Kinds
+                    ui.add_space(top_spacing);
+                    ui.label("The problems that may appear (default: all)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SAMatrixKind::Addition,
+                            SAMatrixKind::Multiplication,
+                            SAMatrixKind::Determinant,
+                            SAMatrixKind::Inverse,
+                        ] {
+                            let mut enabled = self.matrix.kinds.contains(&kind);
+
+                            if ui.checkbox(&mut enabled, kind.as_string()).changed() {
+                                if enabled {
+                                    self.matrix.kinds.push(kind);
+                                } else {
+                                    self.matrix.kinds.retain(|k| *k != kind);
+                                }
+                            }
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_matrices(&self.matrix);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No matrix problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Scientific Notation Generation
+                ui.collapsing("Generate Scientific Notation Problems", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Numeric Options
+                    ui.add_space(top_spacing);
+                    number_input(
+                        ui,
+                        "The number of problems to generate (default: 10)",
+                        &mut self.buffer_space_scientific.num_of_problems,
+                        &mut self.scientific.problems_num,
+                    );
+                    number_input(
+                        ui,
+                        "The lowest possible power of ten (default: -6)",
+                        &mut self.buffer_space_scientific.exponent_floor,
+                        &mut self.scientific.exponent_floor,
+                    );
+                    number_input(
+                        ui,
+                        "The highest possible power of ten (default: 6)",
+                        &mut self.buffer_space_scientific.exponent_ceiling,
+                        &mut self.scientific.exponent_ceiling,
+                    );
+                    number_input(
+                        ui,
+                        "The significant figures to round to, up to 6 (default: 3)",
+                        &mut self.buffer_space_scientific.significant_figures,
+                        &mut self.scientific.significant_figures,
+                    );
+
+                    //Section: Kinds
+                    ui.add_space(top_spacing);
+                    ui.label("The problems that may appear (default: all)");
+                    ui.horizontal(|ui| {
+                        for kind in [
+                            SAScientificKind::ToScientific,
+                            SAScientificKind::FromScientific,
+                            SAScientificKind::Multiplication,
+                            SAScientificKind::Division,
+                            SAScientificKind::SignificantFigures,
+                        ] {
+                            let mut enabled = self.scientific.kinds.contains(&kind);
+
+                            if ui.checkbox(&mut enabled, kind.as_string()).changed() {
+                                if enabled {
+                                    self.scientific.kinds.push(kind);
+                                } else {
+                                    self.scientific.kinds.retain(|k| *k != kind);
+                                }
+                            }
+                        }
+                    });
+
+                    ui.add_space(bottom_spacing);
+
+                    //Section: Generate Button
+                    if ui.add(generate_button("Generate Problems")).clicked() {
+                        let generated_package = sa_generate_scientific(&self.scientific);
+                        self.preview = sa_layout(&generated_package, &self.export, false);
+
+                        self.status = if generated_package.is_empty() {
+                            "Error! No scientific notation problems fit those options!".to_owned()
+                        } else {
+                            export_status(sa_export(generated_package, &self.export))
+                        };
+                    }
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Export Settings
+                ui.collapsing("Export Settings", |ui| {
+                    let top_spacing = 8.0;
+                    let bottom_spacing = 8.0;
+
+                    //Section: Layout
+                    ui.add_space(top_spacing);
+                    ui.label("How problems are laid out in the exported files (default: horizontal)");
+                    ui.horizontal(|ui| {
+                        for layout in [SALayout::Horizontal, SALayout::Vertical] {
+                            ui.radio_value(&mut self.export.layout, layout, layout.as_string());
+                        }
+                    });
+
+                    //Section: Columns
+                    number_input(
+                        ui,
+                        "The number of columns in the vertical layout grid (default: 4)",
+                        &mut self.buffer_space_columns,
+                        &mut self.export.columns,
+                    );
+
+                    //Section: Worked Solutions
+                    ui.add_space(top_spacing);
+                    ui.label("Whether the instructor copy includes worked solutions (default: false)");
+                    ui.checkbox(&mut self.export.worked_steps, "Include Worked Solutions");
+
+                    ui.add_space(bottom_spacing);
+                });
+
+                //---===---===---===---===---===
+                //Segment: Preview
+                ui.collapsing("Preview Last Student Copy", |ui| {
+                    ui.add_space(8.0);
+                    ui.label(egui::RichText::new(&self.preview).monospace());
+                    ui.add_space(8.0);
+                });
+
+                ui.add_space(16.0);
+
+                if ui.add(generate_button("Exit SimAri")).clicked() {
+                    std::process::exit(0);
+                }
+            });
+        });
+    }
+}
+
+fn check_input_num<S1: AsRef<str>>(input: S1) -> SAValidity {
+    match input.as_ref().parse::<i32>() {
+        Ok(n) => SAValidity::ValidNumber(n),
+        Err(_) => SAValidity::InvalidNumber,
+    }
+}
+
+///Labeled single-line number entry, committing to `target` once Enter is pressed on a valid number.
+fn number_input(ui: &mut egui::Ui, label: &str, buffer: &mut String, target: &mut i32) {
+    ui.add_space(8.0);
+    ui.label(label);
+    let resp = ui.add(TextEdit::singleline(buffer));
+
+    if resp.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+        println!("{label} Input: {buffer}");
+        match check_input_num(&buffer) {
+            SAValidity::ValidNumber(n) => {
+                println!("Conversion successful, valid number!");
+                *target = n;
+            }
+            SAValidity::InvalidNumber => {
+                println!("Conversion unsuccessful, invalid number!");
+            }
+        }
+    }
+}
+
+fn operation_radio(ui: &mut egui::Ui, target: &mut SAOperation) {
+    ui.horizontal(|ui| {
+        for operation in [
+            SAOperation::Addition,
+            SAOperation::Subtraction,
+            SAOperation::Multiplication,
+            SAOperation::Division,
+        ] {
+            ui.radio_value(target, operation, operation.as_string());
+        }
+    });
+}
+
+fn export_status(result: anyhow::Result<(String, String)>) -> String {
+    match result {
+        Ok((sfn, ifn)) => format!("Success!\nExported to files:\n{sfn}\n{ifn}"),
+        Err(e) => format!("Error! Could not export to files!\nRaw error: {e}"),
     }
 }
 
@@ -1,12 +1,71 @@
 use rand::prelude::*;
 
+mod base;
+mod choices;
+mod clock;
+mod comparison;
+mod equation;
+mod figure;
+mod fraction;
+mod geometry;
+mod layout;
+mod matrix;
+mod money;
+mod percent;
+mod place_value;
+mod polynomial;
+mod practice;
+mod probability;
+mod roman;
+mod rounding;
+mod scientific;
+mod sequence;
+mod statistics;
+mod system;
+mod word;
+mod worked;
+
+pub use base::*;
+pub use choices::*;
+pub use clock::*;
+pub use comparison::*;
+pub use equation::*;
+pub use figure::*;
+pub use fraction::*;
+pub use geometry::*;
+pub use layout::*;
+pub use matrix::*;
+pub use money::*;
+pub use percent::*;
+pub use place_value::*;
+pub use polynomial::*;
+pub use practice::*;
+pub use probability::*;
+pub use roman::*;
+pub use rounding::*;
+pub use scientific::*;
+pub use sequence::*;
+pub use statistics::*;
+pub use system::*;
+pub use word::*;
+pub use worked::*;
+
 #[derive(Default)]
 pub struct SAGeneratorOptions {
     pub problems_num: i32,
     pub problems_ceiling: i32,
     pub problems_floor: i32,
     pub allow_negatives: bool,
+    pub multiple_choice: bool,
     pub operation: SAOperation,
+    pub figure: SAFigure,
+}
+
+///Settings for how sa_export() lays out the exported files.
+pub struct SAExportOptions {
+    pub layout: SALayout,
+    pub columns: i32,
+    pub worked_steps: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
@@ -24,6 +83,8 @@ pub struct SAProblem {
     pub constant2: i32,
     pub operation: SAOperation,
     pub result: i32,
+    pub choices: Option<SAChoices>,
+    pub figure: SAFigure,
 }
 
 ///Utilized for holding user-entered data and testing conversion to a number. Only once it's
@@ -39,6 +100,29 @@ pub enum SAValidity {
     InvalidNumber,
 }
 
+///Anything sa_export() can write out. Every problem kind renders a line for the student copy and a
+///line for the instructor copy, and names itself for the exported filenames.
+pub trait SARender {
+    fn tag(&self) -> String;
+    fn student_string(&self) -> String;
+    fn instructor_string(&self) -> String;
+
+    ///Stacked rendering used by SALayout::Vertical, for kinds that have one.
+    fn vertical_block(&self, _show_answer: bool) -> Option<Vec<String>> {
+        None
+    }
+
+    ///Step-by-step working for the instructor copy; empty for kinds with nothing to show.
+    fn worked_steps(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    ///SVG figure to go alongside the problem, written out as its own file by sa_export().
+    fn diagram(&self, _instructor: bool) -> Option<String> {
+        None
+    }
+}
+
 impl SAGeneratorOptions {
     pub fn new() -> Self {
         SAGeneratorOptions {
@@ -46,7 +130,9 @@ impl SAGeneratorOptions {
             problems_ceiling: 0,
             problems_floor: 0,
             allow_negatives: false,
+            multiple_choice: false,
             operation: SAOperation::Addition,
+            figure: SAFigure::new(),
         }
     }
 
@@ -55,6 +141,7 @@ impl SAGeneratorOptions {
         problems_ceiling: i32,
         problems_floor: i32,
         allow_negatives: bool,
+        multiple_choice: bool,
         operation: SAOperation,
     ) -> SAGeneratorOptions {
         SAGeneratorOptions {
@@ -62,11 +149,29 @@ impl SAGeneratorOptions {
             problems_ceiling,
             problems_floor,
             allow_negatives,
+            multiple_choice,
             operation,
+            figure: SAFigure::None,
+        }
+    }
+}
+
+impl SAExportOptions {
+    pub fn new() -> Self {
+        SAExportOptions {
+            layout: SALayout::new(),
+            columns: 1,
+            worked_steps: false,
         }
     }
 }
 
+impl Default for SAExportOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl SAOperation {
     pub fn new() -> Self {
         SAOperation::Addition
@@ -98,6 +203,18 @@ impl SAOperation {
         }
     }
 
+    ///SAOperation::calculate() - applies the operation to the given constants.
+    ///
+    ///Division is integer division, same as what ends up in SAProblem::result.
+    pub fn calculate(&self, constant1: i32, constant2: i32) -> i32 {
+        match self {
+            SAOperation::Addition => constant1 + constant2,
+            SAOperation::Subtraction => constant1 - constant2,
+            SAOperation::Multiplication => constant1 * constant2,
+            SAOperation::Division => constant1 / constant2,
+        }
+    }
+
     pub fn as_symbol(&self) -> char {
         match self {
             SAOperation::Addition => '+',
@@ -122,38 +239,82 @@ impl SAProblem {
             constant2: 0,
             operation: SAOperation::new(),
             result: 0,
+            choices: None,
+            figure: SAFigure::new(),
         }
     }
+}
 
-    pub fn student_string(&self) -> String {
-        let operation_char = self.operation.as_string().chars().nth(0).unwrap();
+impl SARender for SAProblem {
+    fn tag(&self) -> String {
+        self.operation.as_string()
+    }
 
-        format!(
+    fn student_string(&self) -> String {
+        let operation_char = self.operation.as_string().chars().nth(0).unwrap();
+        let mut output = format!(
             "Problem {0}{1}: {2} {3} {4} = \n",
             operation_char,
             self.number,
             self.constant1,
             self.operation.as_symbol(),
             self.constant2
-        )
+        );
+
+        for line in self.long_division_bracket(false).unwrap_or_default() {
+            output.push_str(&format!("    {line}\n"));
+        }
+
+        if let Some(choices) = self.choices {
+            output.push_str(&choices.as_string());
+        }
+
+        output
     }
 
-    pub fn instructor_string(&self) -> String {
+    fn instructor_string(&self) -> String {
         //Really, it's pretty stupid that for cases like this I seemingly
         //cannot just index the resultant string and instead have to query it
         //like some uncertain operation. This should only be logically required
         //if we're operating with one-way-blind strings or something.
         let operation_char = self.operation.as_string().chars().nth(0).unwrap();
-
-        format!(
-            "Problem {0}{1}: {2} {3} {4} = {5}\n",
+        let choice_marker = match self.choices {
+            Some(choices) => format!(" ({0})", choices.correct_letter()),
+            None => "".to_owned(),
+        };
+        let mut output = format!(
+            "Problem {0}{1}: {2} {3} {4} = {5}{6}\n",
             operation_char,
             self.number,
             self.constant1,
             self.operation.as_symbol(),
             self.constant2,
-            self.result
-        )
+            self.result,
+            choice_marker
+        );
+
+        //Division always gets its worked bracket here, whatever the layout or worked_steps say.
+        for line in self.long_division_bracket(true).unwrap_or_default() {
+            output.push_str(&format!("    {line}\n"));
+        }
+
+        if let Some(choices) = self.choices {
+            output.push_str(&choices.as_string());
+        }
+
+        output
+    }
+
+    fn vertical_block(&self, show_answer: bool) -> Option<Vec<String>> {
+        self.vertical_lines(show_answer)
+    }
+
+    fn worked_steps(&self) -> Vec<String> {
+        self.worked_solution()
+    }
+
+    fn diagram(&self, instructor: bool) -> Option<String> {
+        self.figure_svg(instructor)
     }
 }
 
@@ -179,7 +340,10 @@ impl Default for SAProblemInputBuffer {
     }
 }
 
-pub fn sa_export(data: Vec<SAProblem>) -> anyhow::Result<(String, String)> {
+pub fn sa_export<P: SARender>(
+    data: Vec<P>,
+    options: &SAExportOptions,
+) -> anyhow::Result<(String, String)> {
     use rand::distributions::{Alphanumeric, DistString};
     use std::{
         fs::File,
@@ -187,11 +351,11 @@ pub fn sa_export(data: Vec<SAProblem>) -> anyhow::Result<(String, String)> {
     };
 
     let tag = {
-        let operation_buffer = data[0].operation;
-        let mut string_buffer = data[0].operation.as_string();
+        let operation_buffer = data[0].tag();
+        let mut string_buffer = data[0].tag();
 
         for problem in &data {
-            if operation_buffer != problem.operation {
+            if operation_buffer != problem.tag() {
                 string_buffer = "Mixed".to_owned();
                 break;
             } else {
@@ -208,56 +372,127 @@ pub fn sa_export(data: Vec<SAProblem>) -> anyhow::Result<(String, String)> {
     let mut student_file_out = LineWriter::new(File::create(&student_filename)?);
     let mut instructor_file_out = LineWriter::new(File::create(&instructor_filename)?);
 
-    for problem in data {
-        student_file_out.write_all(problem.student_string().as_bytes())?;
-        instructor_file_out.write_all(problem.instructor_string().as_bytes())?;
+    student_file_out.write_all(sa_layout(&data, options, false).as_bytes())?;
+    instructor_file_out.write_all(sa_layout(&data, options, true).as_bytes())?;
+
+    //Figures go in their own SVG files next to the text, listed at the end so they can be
+    //matched up with the 'See Figure N' in each problem.
+    let mut student_figures = String::new();
+    let mut instructor_figures = String::new();
+
+    for (index, problem) in data.iter().enumerate() {
+        let figure = index + 1;
+
+        for (instructor, prefix, listing) in [
+            (false, "Student", &mut student_figures),
+            (true, "Instructor", &mut instructor_figures),
+        ] {
+            if let Some(svg) = problem.diagram(instructor) {
+                let filename =
+                    format!("SimAri_{prefix}_{timestamp}_{tag}_{keycode}_Figure{figure}.svg");
+                File::create(&filename)?.write_all(svg.as_bytes())?;
+                listing.push_str(&format!("Figure {figure}: {filename}\n"));
+            }
+        }
+    }
+
+    if !student_figures.is_empty() {
+        let header = "\n---===---===---===---===---===\nFigures\n\n";
+        student_file_out.write_all(format!("{header}{student_figures}").as_bytes())?;
+        instructor_file_out.write_all(format!("{header}{instructor_figures}").as_bytes())?;
+    }
+
+    if options.worked_steps {
+        instructor_file_out.write_all(sa_worked_section(&data).as_bytes())?;
     }
 
     Ok((student_filename, instructor_filename))
 }
 
 pub fn sa_generate(options: &SAGeneratorOptions) -> Vec<SAProblem> {
+    sa_generate_with_review(options, &[])
+}
+
+///sa_generate_with_review() - starts the package with the given review problems (e.g. the facts a
+///student has due or keeps missing), then fills the rest with fresh problems. Fresh problems are
+///kept apart from each other by the usual spacing rules until those have turned away 1,000
+///candidates, after which only repeats are refused, since small ranges can't satisfy them. Gives
+///up after 10,000 attempts, so callers should compare the length against problems_num.
+pub fn sa_generate_with_review(
+    options: &SAGeneratorOptions,
+    review: &[SAProblem],
+) -> Vec<SAProblem> {
     use rand::distributions::Uniform;
 
-    let mut generated_package = Vec::new();
+    let mut generated_package: Vec<SAProblem> = review
+        .iter()
+        .take(options.problems_num.max(0) as usize)
+        .copied()
+        .collect();
     let mut used_number_buffer = Vec::new();
     let mut used_answer_buffer = Vec::new();
     let mut last_constant1 = 0i32;
     let mut last_constant2 = 0i32;
     let perc = ((options.problems_ceiling - options.problems_floor) / 100) * 12;
+    let mut attempts = 0;
+
+    for problem in generated_package.iter_mut() {
+        problem.figure = options.figure;
+        used_number_buffer.push(problem.constant1);
+        used_number_buffer.push(problem.constant2);
+        used_answer_buffer.push(problem.result);
+    }
+
+    if options.problems_ceiling < options.problems_floor {
+        return generated_package;
+    }
+
+    let range = Uniform::from(options.problems_floor..=options.problems_ceiling);
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
 
-    while generated_package.len() < options.problems_num as usize {
         let mut rng1 = thread_rng();
         let mut rng2 = thread_rng();
-        let constant1 =
-            Uniform::from(options.problems_floor..=options.problems_ceiling).sample(&mut rng1);
-        let constant2 =
-            Uniform::from(options.problems_floor..=options.problems_ceiling).sample(&mut rng2);
+        let constant1 = range.sample(&mut rng1);
+        let constant2 = range.sample(&mut rng2);
+
+        if options.operation == SAOperation::Division && constant2 == 0 {
+            continue;
+        }
+
         let problem: SAProblem = SAProblem {
-            number: generated_package.len() as i32 + 1,
+            number: 0,
             constant1,
             constant2,
             operation: options.operation,
-            result: match options.operation {
-                SAOperation::Addition => constant1 + constant2,
-                SAOperation::Subtraction => constant1 - constant2,
-                SAOperation::Multiplication => constant1 * constant2,
-                SAOperation::Division => constant1 / constant2,
-            },
+            result: options.operation.calculate(constant1, constant2),
+            choices: None,
+            figure: options.figure,
         };
 
-        if (problem.constant1 - perc..=problem.constant1 + perc).contains(&problem.constant2)
-            || (problem.result <= 0 && !options.allow_negatives)
+        if (problem.result <= 0 && !options.allow_negatives)
+            || generated_package.iter().any(|p| {
+                p.constant1 == problem.constant1
+                    && p.constant2 == problem.constant2
+                    && p.operation == problem.operation
+            })
         {
             continue;
         }
 
-        if generated_package.len() > 1 {
-            if (problem.constant1 - perc..=problem.constant1 + perc).contains(&last_constant1)
-                || (problem.constant2 - perc..=problem.constant2 + perc).contains(&last_constant2)
-                || used_number_buffer.contains(&problem.constant1)
-                || used_number_buffer.contains(&problem.constant2)
-                || used_answer_buffer.contains(&problem.result)
+        if attempts < 1_000 {
+            if (problem.constant1 - perc..=problem.constant1 + perc).contains(&problem.constant2) {
+                continue;
+            }
+
+            if generated_package.len() > 1
+                && ((problem.constant1 - perc..=problem.constant1 + perc).contains(&last_constant1)
+                    || (problem.constant2 - perc..=problem.constant2 + perc)
+                        .contains(&last_constant2)
+                    || used_number_buffer.contains(&problem.constant1)
+                    || used_number_buffer.contains(&problem.constant2)
+                    || used_answer_buffer.contains(&problem.result))
             {
                 continue;
             }
@@ -272,5 +507,13 @@ pub fn sa_generate(options: &SAGeneratorOptions) -> Vec<SAProblem> {
         generated_package.push(problem);
     }
 
+    for (index, problem) in generated_package.iter_mut().enumerate() {
+        problem.number = index as i32 + 1;
+
+        if options.multiple_choice {
+            problem.choices = Some(SAChoices::generate(problem));
+        }
+    }
+
     generated_package
 }
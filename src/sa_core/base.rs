@@ -0,0 +1,301 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SANumberBase {
+    Binary,
+    Octal,
+    Hexadecimal,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SABaseKind {
+    ///'Convert 45 to binary (8-bit).'
+    ToBase,
+    ///'Convert 00101101 (binary, 8-bit) to decimal.'
+    FromBase,
+    ///'00101101 + 00010110 = '
+    BinaryAddition,
+}
+
+///Options for base conversion problems. Every value fits in `bit_width` bits; with
+///`allow_negatives`, values are signed and written in two's complement.
+pub struct SABaseOptions {
+    pub problems_num: i32,
+    pub kind: SABaseKind,
+    pub base: SANumberBase,
+    pub bit_width: i32,
+    pub allow_negatives: bool,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SABaseProblem {
+    pub number: i32,
+    pub kind: SABaseKind,
+    pub base: SANumberBase,
+    pub bit_width: u32,
+    pub signed: bool,
+    pub value: i64,
+    ///Second addend, only used by BinaryAddition.
+    pub addend: i64,
+}
+
+///Input buffer for the base options, see SAProblemInputBuffer.
+pub struct SABaseInputBuffer {
+    pub num_of_problems: String,
+    pub bit_width: String,
+}
+
+impl SANumberBase {
+    pub fn new() -> Self {
+        SANumberBase::Binary
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SANumberBase::Binary => "Binary".to_owned(),
+            SANumberBase::Octal => "Octal".to_owned(),
+            SANumberBase::Hexadecimal => "Hexadecimal".to_owned(),
+        }
+    }
+
+    ///Digits needed to write any value of the given bit width in this base.
+    pub fn digits_for(&self, bit_width: u32) -> usize {
+        match self {
+            SANumberBase::Binary => bit_width as usize,
+            SANumberBase::Octal => bit_width.div_ceil(3) as usize,
+            SANumberBase::Hexadecimal => bit_width.div_ceil(4) as usize,
+        }
+    }
+
+    ///SANumberBase::format() - writes out the bit pattern of the value, zero-padded to the full
+    ///width, e.g. -3 at 8 bits -> '11111101' or 'FD'.
+    pub fn format(&self, value: i64, bit_width: u32) -> String {
+        let pattern = (value as u64) & sa_bit_mask(bit_width);
+        let width = self.digits_for(bit_width);
+
+        match self {
+            SANumberBase::Binary => format!("{pattern:0width$b}"),
+            SANumberBase::Octal => format!("{pattern:0width$o}"),
+            SANumberBase::Hexadecimal => format!("{pattern:0width$X}"),
+        }
+    }
+}
+
+impl Default for SANumberBase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SABaseKind {
+    pub fn new() -> Self {
+        SABaseKind::ToBase
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SABaseKind::ToBase => "Decimal to Base".to_owned(),
+            SABaseKind::FromBase => "Base to Decimal".to_owned(),
+            SABaseKind::BinaryAddition => "Binary Addition".to_owned(),
+        }
+    }
+}
+
+impl Default for SABaseKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SABaseOptions {
+    pub fn new() -> Self {
+        SABaseOptions {
+            problems_num: 0,
+            kind: SABaseKind::new(),
+            base: SANumberBase::new(),
+            bit_width: 8,
+            allow_negatives: false,
+        }
+    }
+}
+
+impl Default for SABaseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn sa_bit_mask(bit_width: u32) -> u64 {
+    if bit_width >= 64 {
+        u64::MAX
+    } else {
+        (1u64 << bit_width) - 1
+    }
+}
+
+///The smallest and largest values that fit in the bit width, signed or not.
+fn sa_bit_range(bit_width: u32, signed: bool) -> (i64, i64) {
+    if signed {
+        (-(1i64 << (bit_width - 1)), (1i64 << (bit_width - 1)) - 1)
+    } else {
+        (0, (1i64 << bit_width) - 1)
+    }
+}
+
+impl SABaseProblem {
+    ///Width of the widest decimal value in range, so decimals line up down the page.
+    fn decimal_width(&self) -> usize {
+        let (low, high) = sa_bit_range(self.bit_width, self.signed);
+
+        low.to_string().len().max(high.to_string().len())
+    }
+
+    fn width_note(&self) -> String {
+        if self.signed {
+            format!("{0}-bit two's complement", self.bit_width)
+        } else {
+            format!("{0}-bit", self.bit_width)
+        }
+    }
+
+    fn pattern(&self, value: i64) -> String {
+        let base = match self.kind {
+            SABaseKind::BinaryAddition => SANumberBase::Binary,
+            _ => self.base,
+        };
+
+        base.format(value, self.bit_width)
+    }
+
+    fn question(&self) -> String {
+        let width = self.decimal_width();
+
+        match self.kind {
+            SABaseKind::ToBase => format!(
+                "Convert {0:>width$} to {1} ({2}):",
+                self.value,
+                self.base.as_string().to_lowercase(),
+                self.width_note()
+            ),
+            SABaseKind::FromBase => format!(
+                "Convert {0} ({1}, {2}) to decimal:",
+                self.pattern(self.value),
+                self.base.as_string().to_lowercase(),
+                self.width_note()
+            ),
+            SABaseKind::BinaryAddition => format!(
+                "{0} + {1} =",
+                self.pattern(self.value),
+                self.pattern(self.addend)
+            ),
+        }
+    }
+
+    pub fn answer(&self) -> String {
+        match self.kind {
+            SABaseKind::ToBase => self.pattern(self.value),
+            SABaseKind::FromBase => format!("{0:>1$}", self.value, self.decimal_width()),
+            SABaseKind::BinaryAddition => self.pattern(self.value + self.addend),
+        }
+    }
+}
+
+impl SARender for SABaseProblem {
+    fn tag(&self) -> String {
+        match self.kind {
+            SABaseKind::BinaryAddition => "BinaryAddition".to_owned(),
+            _ => self.base.as_string(),
+        }
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem B{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem B{0}: {1} {2}\n",
+            self.number,
+            self.question(),
+            self.answer()
+        )
+    }
+
+    fn vertical_block(&self, show_answer: bool) -> Option<Vec<String>> {
+        if self.kind != SABaseKind::BinaryAddition {
+            return None;
+        }
+
+        let width = self.bit_width as usize;
+        let answer = if show_answer {
+            format!("  {0}", self.answer())
+        } else {
+            " ".repeat(width + 2)
+        };
+
+        Some(vec![
+            format!("B{0}.", self.number),
+            format!("  {0}", self.pattern(self.value)),
+            format!("+ {0}", self.pattern(self.addend)),
+            "-".repeat(width + 2),
+            answer,
+        ])
+    }
+}
+
+impl SABaseInputBuffer {
+    pub fn new() -> Self {
+        SABaseInputBuffer {
+            num_of_problems: "".to_owned(),
+            bit_width: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SABaseInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn sa_generate_base(options: &SABaseOptions) -> Vec<SABaseProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SABaseProblem> = Vec::new();
+    let bit_width = options.bit_width.clamp(2, 32) as u32;
+    let (low, high) = sa_bit_range(bit_width, options.allow_negatives);
+    let values = Uniform::from(low..=high);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let value = values.sample(&mut rng);
+        let addend = match options.kind {
+            SABaseKind::BinaryAddition => values.sample(&mut rng),
+            _ => 0,
+        };
+
+        //Sums that overflow the bit width would need the wraparound explained, so skip them.
+        if !(low..=high).contains(&(value + addend))
+            || generated_package
+                .iter()
+                .any(|p| p.value == value && p.addend == addend)
+        {
+            continue;
+        }
+
+        generated_package.push(SABaseProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            base: options.base,
+            bit_width,
+            signed: options.allow_negatives,
+            value,
+            addend,
+        });
+    }
+
+    generated_package
+}
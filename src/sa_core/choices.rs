@@ -0,0 +1,179 @@
+use super::{SAOperation, SAProblem};
+use rand::prelude::*;
+
+///Answer choices for a multiple-choice problem. Stored inline so SAProblem stays Copy; only the
+///first `count` entries of `options` are used.
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub struct SAChoices {
+    pub options: [i32; 4],
+    pub count: usize,
+    pub correct: usize,
+}
+
+///Column-wise sum or difference with every carry or borrow dropped, e.g. 48 + 35 = 73 or
+///52 - 38 = 26 (smaller digit taken from larger).
+fn sa_forgotten_regroup(a: i32, b: i32, operation: SAOperation) -> i32 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    let mut place = 1;
+    let mut result = 0;
+
+    while a > 0 || b > 0 {
+        let (da, db) = (a % 10, b % 10);
+        let digit = match operation {
+            SAOperation::Subtraction => (da - db).abs(),
+            _ => (da + db) % 10,
+        };
+
+        result += digit * place;
+        a /= 10;
+        b /= 10;
+        place *= 10;
+    }
+
+    result
+}
+
+///Multiplication by a single digit with the carries dropped, or multi-digit multiplication with the
+///partial products left unshifted.
+fn sa_forgotten_carry_product(a: i32, b: i32) -> i32 {
+    let (a, b) = (a.abs(), b.abs());
+
+    if b < 10 {
+        let mut remaining = a;
+        let mut place = 1;
+        let mut result = 0;
+
+        while remaining > 0 {
+            result += (remaining % 10 * b) % 10 * place;
+            remaining /= 10;
+            place *= 10;
+        }
+
+        result
+    } else {
+        let mut remaining = b;
+        let mut result = 0;
+
+        while remaining > 0 {
+            result += a * (remaining % 10);
+            remaining /= 10;
+        }
+
+        result
+    }
+}
+
+fn sa_reversed_digits(n: i32) -> i32 {
+    let reversed: String = n.abs().to_string().chars().rev().collect();
+
+    reversed.parse::<i32>().unwrap_or(n) * n.signum()
+}
+
+impl SAChoices {
+    ///SAChoices::generate() - builds 3-4 choices for the problem, in random order.
+    ///
+    ///Distractors come from common error models (a forgotten carry or borrow, an off-by-one, the
+    ///wrong operation, reversed digits) rather than random numbers. Only if those collapse into
+    ///too few distinct answers do we fall back to nearby values.
+    pub fn generate(problem: &SAProblem) -> SAChoices {
+        let mut rng = thread_rng();
+        let (a, b, result) = (problem.constant1, problem.constant2, problem.result);
+        let mut candidates = vec![sa_reversed_digits(result)];
+
+        match problem.operation {
+            SAOperation::Addition => {
+                candidates.push(sa_forgotten_regroup(a, b, problem.operation));
+                candidates.push(result + 1);
+                candidates.push(result - 1);
+                candidates.push(a - b);
+                candidates.push(result + 10);
+            }
+            SAOperation::Subtraction => {
+                candidates.push(sa_forgotten_regroup(a, b, problem.operation));
+                candidates.push(result + 1);
+                candidates.push(result - 1);
+                candidates.push(a + b);
+                candidates.push(result - 10);
+            }
+            SAOperation::Multiplication => {
+                candidates.push(sa_forgotten_carry_product(a, b));
+                candidates.push(result + a);
+                candidates.push(result - a);
+                candidates.push(a + b);
+                candidates.push(result + 10);
+            }
+            SAOperation::Division => {
+                candidates.push(result + 1);
+                candidates.push(result - 1);
+                candidates.push(a - b);
+                candidates.push(result * 10);
+            }
+        }
+
+        let mut distractors: Vec<i32> = Vec::new();
+
+        for candidate in candidates {
+            if candidate != result
+                && (candidate >= 0 || result < 0)
+                && !distractors.contains(&candidate)
+            {
+                distractors.push(candidate);
+            }
+        }
+
+        //Pick among the most plausible mistakes so the same ones don't show up on every problem.
+        distractors.truncate(5);
+        distractors.shuffle(&mut rng);
+        distractors.truncate(3);
+
+        let mut offset = 2;
+
+        while distractors.len() < 2 {
+            if !distractors.contains(&(result + offset)) {
+                distractors.push(result + offset);
+            }
+
+            offset += 1;
+        }
+
+        let count = distractors.len() + 1;
+        let correct = rng.gen_range(0..count);
+        let mut options = [0i32; 4];
+        let mut wrong = distractors.into_iter();
+
+        for (index, option) in options.iter_mut().enumerate().take(count) {
+            *option = if index == correct {
+                result
+            } else {
+                wrong.next().unwrap()
+            };
+        }
+
+        SAChoices {
+            options,
+            count,
+            correct,
+        }
+    }
+
+    pub fn letter(index: usize) -> char {
+        (b'a' + index as u8) as char
+    }
+
+    pub fn correct_letter(&self) -> char {
+        SAChoices::letter(self.correct)
+    }
+
+    ///Each choice as 'a) 168', in order.
+    pub fn labels(&self) -> Vec<String> {
+        self.options[..self.count]
+            .iter()
+            .enumerate()
+            .map(|(index, option)| format!("{0}) {option}", SAChoices::letter(index)))
+            .collect()
+    }
+
+    pub fn as_string(&self) -> String {
+        format!("    {0}\n", self.labels().join("    "))
+    }
+}
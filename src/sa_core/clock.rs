@@ -0,0 +1,278 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+const SA_MINUTES_PER_DAY: i32 = 24 * 60;
+const SA_NOON: i32 = 12 * 60;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAClockFormat {
+    ///'2:45 PM'
+    TwelveHour,
+    ///'14:45'
+    TwentyFourHour,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAClockKind {
+    ///'It is 2:45 PM. What time will it be in 1 hour 35 minutes?'
+    AddDuration,
+    ///'How much time passes from 2:45 PM to 4:20 PM?'
+    Elapsed,
+}
+
+///Whether the span between the two times may pass 12:00 noon, which is where the AM/PM switch
+///trips students up on a 12 hour clock.
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SANoonCrossing {
+    Never,
+    Allowed,
+    Always,
+}
+
+///Options for clock problems. Every time and duration lands on a multiple of `granularity`
+///minutes (1, 5 or 15), and durations run up to `max_duration` minutes. Problems never run past
+///midnight.
+pub struct SAClockOptions {
+    pub problems_num: i32,
+    pub kind: SAClockKind,
+    pub format: SAClockFormat,
+    pub granularity: i32,
+    pub max_duration: i32,
+    pub noon: SANoonCrossing,
+}
+
+///A clock problem, with `start` in minutes since midnight and `duration` in minutes.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAClockProblem {
+    pub number: i32,
+    pub kind: SAClockKind,
+    pub format: SAClockFormat,
+    pub start: i32,
+    pub duration: i32,
+}
+
+///Input buffer for the clock options, see SAProblemInputBuffer.
+pub struct SAClockInputBuffer {
+    pub num_of_problems: String,
+    pub max_duration: String,
+}
+
+impl SAClockFormat {
+    pub fn new() -> Self {
+        SAClockFormat::TwelveHour
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAClockFormat::TwelveHour => "12 Hour".to_owned(),
+            SAClockFormat::TwentyFourHour => "24 Hour".to_owned(),
+        }
+    }
+
+    ///SAClockFormat::format() - writes out minutes since midnight as a clock time, e.g. 885 ->
+    ///'2:45 PM' or '14:45'.
+    pub fn format(&self, minutes: i32) -> String {
+        let minutes = minutes.rem_euclid(SA_MINUTES_PER_DAY);
+        let (hour, minute) = (minutes / 60, minutes % 60);
+
+        match self {
+            SAClockFormat::TwelveHour => {
+                let suffix = if hour < 12 { "AM" } else { "PM" };
+                let hour = match hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+
+                format!("{hour}:{minute:02} {suffix}")
+            }
+            SAClockFormat::TwentyFourHour => format!("{hour:02}:{minute:02}"),
+        }
+    }
+}
+
+impl Default for SAClockFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAClockKind {
+    pub fn new() -> Self {
+        SAClockKind::AddDuration
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAClockKind::AddDuration => "Time After".to_owned(),
+            SAClockKind::Elapsed => "Elapsed Time".to_owned(),
+        }
+    }
+}
+
+impl Default for SAClockKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SANoonCrossing {
+    pub fn new() -> Self {
+        SANoonCrossing::Allowed
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SANoonCrossing::Never => "Never".to_owned(),
+            SANoonCrossing::Allowed => "Allowed".to_owned(),
+            SANoonCrossing::Always => "Always".to_owned(),
+        }
+    }
+}
+
+impl Default for SANoonCrossing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAClockOptions {
+    pub fn new() -> Self {
+        SAClockOptions {
+            problems_num: 0,
+            kind: SAClockKind::new(),
+            format: SAClockFormat::new(),
+            granularity: 5,
+            max_duration: 0,
+            noon: SANoonCrossing::new(),
+        }
+    }
+}
+
+impl Default for SAClockOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///sa_duration_string() - writes out a duration in minutes, e.g. 95 -> '1 hour 35 minutes'.
+pub fn sa_duration_string(minutes: i32) -> String {
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    let hours_part = match hours {
+        1 => "1 hour".to_owned(),
+        h => format!("{h} hours"),
+    };
+    let minutes_part = match minutes {
+        1 => "1 minute".to_owned(),
+        m => format!("{m} minutes"),
+    };
+
+    match (hours, minutes) {
+        (0, _) => minutes_part,
+        (_, 0) => hours_part,
+        _ => format!("{hours_part} {minutes_part}"),
+    }
+}
+
+impl SAClockProblem {
+    pub fn end(&self) -> i32 {
+        self.start + self.duration
+    }
+
+    fn question(&self) -> String {
+        match self.kind {
+            SAClockKind::AddDuration => format!(
+                "It is {0}. What time will it be in {1}?",
+                self.format.format(self.start),
+                sa_duration_string(self.duration)
+            ),
+            SAClockKind::Elapsed => format!(
+                "How much time passes from {0} to {1}?",
+                self.format.format(self.start),
+                self.format.format(self.end())
+            ),
+        }
+    }
+}
+
+impl SARender for SAClockProblem {
+    fn tag(&self) -> String {
+        "Clock".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem T{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        let answer = match self.kind {
+            SAClockKind::AddDuration => self.format.format(self.end()),
+            SAClockKind::Elapsed => sa_duration_string(self.duration),
+        };
+
+        format!(
+            "Problem T{0}: {1} Answer: {2}\n",
+            self.number,
+            self.question(),
+            answer
+        )
+    }
+}
+
+impl SAClockInputBuffer {
+    pub fn new() -> Self {
+        SAClockInputBuffer {
+            num_of_problems: "".to_owned(),
+            max_duration: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAClockInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn sa_generate_clock(options: &SAClockOptions) -> Vec<SAClockProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAClockProblem> = Vec::new();
+    let granularity = options.granularity.clamp(1, 60);
+    let steps = options.max_duration.min(SA_MINUTES_PER_DAY - 1) / granularity;
+
+    if steps < 1 {
+        return generated_package;
+    }
+
+    let starts = Uniform::from(0..SA_MINUTES_PER_DAY / granularity);
+    let durations = Uniform::from(1..=steps);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let start = starts.sample(&mut rng) * granularity;
+        let duration = durations.sample(&mut rng) * granularity;
+        let crosses_noon = start < SA_NOON && start + duration >= SA_NOON;
+
+        if start + duration >= SA_MINUTES_PER_DAY
+            || (options.noon == SANoonCrossing::Never && crosses_noon)
+            || (options.noon == SANoonCrossing::Always && !crosses_noon)
+            || generated_package
+                .iter()
+                .any(|p| p.start == start && p.duration == duration)
+        {
+            continue;
+        }
+
+        generated_package.push(SAClockProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            format: options.format,
+            start,
+            duration,
+        });
+    }
+
+    generated_package
+}
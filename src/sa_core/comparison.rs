@@ -0,0 +1,235 @@
+use super::{SAGeneratorOptions, SAOperation, SARender};
+use rand::distributions::{Uniform, WeightedIndex};
+use rand::prelude::*;
+use std::cmp::Ordering;
+
+///One side of a comparison, either a plain number or a small expression like '5 + 3'.
+#[derive(Copy, Clone, PartialEq)]
+pub enum SAComparisonSide {
+    Number(i32),
+    Expression(i32, SAOperation, i32),
+}
+
+///Options for comparison problems. The operand range, negatives and the operation used in
+///expressions all come from `base`, same as the regular arithmetic options. The three weights set
+///the ratio of less-than, greater-than and equal answers.
+pub struct SAComparisonOptions {
+    pub base: SAGeneratorOptions,
+    pub less_weight: i32,
+    pub greater_weight: i32,
+    pub equal_weight: i32,
+    pub use_expressions: bool,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAComparisonProblem {
+    pub number: i32,
+    pub left: SAComparisonSide,
+    pub right: SAComparisonSide,
+    pub relation: Ordering,
+}
+
+///Input buffer for the comparison options, see SAProblemInputBuffer.
+pub struct SAComparisonInputBuffer {
+    pub num_of_problems: String,
+    pub floor: String,
+    pub ceiling: String,
+    pub less_weight: String,
+    pub greater_weight: String,
+    pub equal_weight: String,
+}
+
+impl SAComparisonSide {
+    pub fn value(&self) -> i32 {
+        match self {
+            SAComparisonSide::Number(n) => *n,
+            SAComparisonSide::Expression(a, operation, b) => operation.calculate(*a, *b),
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAComparisonSide::Number(n) => n.to_string(),
+            SAComparisonSide::Expression(a, operation, b) => {
+                format!("{a} {0} {b}", operation.as_symbol())
+            }
+        }
+    }
+}
+
+impl SAComparisonOptions {
+    pub fn new() -> Self {
+        SAComparisonOptions {
+            base: SAGeneratorOptions::new(),
+            less_weight: 1,
+            greater_weight: 1,
+            equal_weight: 1,
+            use_expressions: false,
+        }
+    }
+}
+
+impl Default for SAComparisonOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAComparisonProblem {
+    pub fn relation_symbol(&self) -> char {
+        match self.relation {
+            Ordering::Less => '<',
+            Ordering::Greater => '>',
+            Ordering::Equal => '=',
+        }
+    }
+}
+
+impl SARender for SAComparisonProblem {
+    fn tag(&self) -> String {
+        "Comparison".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!(
+            "Problem C{0}: {1} \u{25CB} {2}\n",
+            self.number,
+            self.left.as_string(),
+            self.right.as_string()
+        )
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem C{0}: {1} {2} {3}\n",
+            self.number,
+            self.left.as_string(),
+            self.relation_symbol(),
+            self.right.as_string()
+        )
+    }
+}
+
+impl SAComparisonInputBuffer {
+    pub fn new() -> Self {
+        SAComparisonInputBuffer {
+            num_of_problems: "".to_owned(),
+            floor: "".to_owned(),
+            ceiling: "".to_owned(),
+            less_weight: "".to_owned(),
+            greater_weight: "".to_owned(),
+            equal_weight: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAComparisonInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Builds a random expression within the given range. Division is always exact, since remainders
+///would make the comparison a matter of how the student rounds.
+fn sa_random_expression(
+    rng: &mut ThreadRng,
+    floor: i32,
+    ceiling: i32,
+    operation: SAOperation,
+) -> SAComparisonSide {
+    let range = Uniform::from(floor..=ceiling);
+
+    match operation {
+        SAOperation::Division => {
+            let divisor = Uniform::from(floor.max(1)..=ceiling.max(1)).sample(rng);
+            let quotient = Uniform::from(1..=(ceiling / divisor).max(1)).sample(rng);
+
+            SAComparisonSide::Expression(divisor * quotient, operation, divisor)
+        }
+        _ => SAComparisonSide::Expression(range.sample(rng), operation, range.sample(rng)),
+    }
+}
+
+pub fn sa_generate_comparisons(options: &SAComparisonOptions) -> Vec<SAComparisonProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAComparisonProblem> = Vec::new();
+    let floor = if options.base.allow_negatives {
+        options.base.problems_floor
+    } else {
+        options.base.problems_floor.max(0)
+    };
+    let ceiling = options.base.problems_ceiling.max(floor);
+    let spread = ((ceiling - floor) / 10).max(1);
+    let weights = [
+        options.less_weight.max(0),
+        options.greater_weight.max(0),
+        options.equal_weight.max(0),
+    ];
+    let relations =
+        WeightedIndex::new(weights).unwrap_or_else(|_| WeightedIndex::new([1, 1, 1]).unwrap());
+    let mut attempts = 0;
+
+    while generated_package.len() < options.base.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let relation =
+            [Ordering::Less, Ordering::Greater, Ordering::Equal][relations.sample(&mut rng)];
+        let left = if options.use_expressions {
+            sa_random_expression(&mut rng, floor, ceiling, options.base.operation)
+        } else {
+            SAComparisonSide::Number(Uniform::from(floor..=ceiling).sample(&mut rng))
+        };
+        let value = left.value();
+        let delta = Uniform::from(1..=spread).sample(&mut rng);
+        let reversed = value
+            .abs()
+            .to_string()
+            .chars()
+            .rev()
+            .collect::<String>()
+            .parse::<i32>()
+            .unwrap_or(value)
+            * value.signum();
+
+        //Swapped digits ('34 vs 43') are the classic comparison mix-up, so use them about half
+        //the time they land on the side we need.
+        let right_value = match relation {
+            Ordering::Equal => value,
+            _ if reversed.cmp(&value) == relation.reverse() && rng.gen() => reversed,
+            Ordering::Less => value + delta,
+            Ordering::Greater => value - delta,
+        };
+
+        //An expression's value can dip below zero even when its operands don't, e.g. 3 - 8.
+        if ((value < 0 || right_value < 0) && !options.base.allow_negatives)
+            || (!options.use_expressions && !(floor..=ceiling).contains(&right_value))
+        {
+            continue;
+        }
+
+        let mut problem = SAComparisonProblem {
+            number: generated_package.len() as i32 + 1,
+            left,
+            right: SAComparisonSide::Number(right_value),
+            relation,
+        };
+
+        //With expressions, put the expression on either side so students read both ways.
+        if options.use_expressions && rng.gen() {
+            problem.left = problem.right;
+            problem.right = left;
+            problem.relation = relation.reverse();
+        }
+
+        if generated_package
+            .iter()
+            .any(|p| p.left == problem.left && p.right == problem.right)
+        {
+            continue;
+        }
+
+        generated_package.push(problem);
+    }
+
+    generated_package
+}
@@ -0,0 +1,269 @@
+use super::{SAOperation, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAEquationSteps {
+    ///'x + 7 = 19', '3x = 27'
+    OneStep,
+    ///'2x - 5 = 11', 'x/4 + 3 = 8'
+    TwoStep,
+}
+
+///Options for linear equations. The floor and ceiling bound the solution, and `operations` lists
+///which operations may appear on the x side. Two-step equations need one of multiplication or
+///division for the coefficient and one of addition or subtraction for the constant.
+pub struct SAEquationOptions {
+    pub problems_num: i32,
+    pub solution_floor: i32,
+    pub solution_ceiling: i32,
+    pub allow_negatives: bool,
+    pub steps: SAEquationSteps,
+    pub operations: Vec<SAOperation>,
+}
+
+///A linear equation in x. The x side is x, then the coefficient applied to it (multiplication or
+///division), then the constant (addition or subtraction); either may be missing in a one-step
+///equation.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAEquationProblem {
+    pub number: i32,
+    pub solution: i32,
+    pub coefficient: Option<(SAOperation, i32)>,
+    pub constant: Option<(SAOperation, i32)>,
+    pub right: i32,
+}
+
+impl SAEquationSteps {
+    pub fn new() -> Self {
+        SAEquationSteps::OneStep
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAEquationSteps::OneStep => "One Step".to_owned(),
+            SAEquationSteps::TwoStep => "Two Step".to_owned(),
+        }
+    }
+}
+
+impl Default for SAEquationSteps {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAEquationOptions {
+    pub fn new() -> Self {
+        SAEquationOptions {
+            problems_num: 0,
+            solution_floor: 0,
+            solution_ceiling: 0,
+            allow_negatives: false,
+            steps: SAEquationSteps::new(),
+            operations: vec![
+                SAOperation::Addition,
+                SAOperation::Subtraction,
+                SAOperation::Multiplication,
+                SAOperation::Division,
+            ],
+        }
+    }
+}
+
+impl Default for SAEquationOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAEquationProblem {
+    ///The x term alone, e.g. 'x', '3x', '-x' or 'x/4'.
+    fn x_term(&self) -> String {
+        match self.coefficient {
+            Some((SAOperation::Multiplication, -1)) => "-x".to_owned(),
+            Some((SAOperation::Multiplication, a)) => format!("{a}x"),
+            Some((_, a)) => format!("x/{a}"),
+            None => "x".to_owned(),
+        }
+    }
+
+    pub fn equation_string(&self) -> String {
+        match self.constant {
+            Some((operation, b)) => format!(
+                "{0} {1} {b} = {2}",
+                self.x_term(),
+                operation.as_symbol(),
+                self.right
+            ),
+            None => format!("{0} = {1}", self.x_term(), self.right),
+        }
+    }
+
+    ///The inverse operations that solve the equation, constant first, e.g.
+    ///'Subtract 5 from both sides: 3x = 6'.
+    pub fn inverse_steps(&self) -> Vec<String> {
+        let mut steps = Vec::new();
+        let mut right = self.right;
+
+        if let Some((operation, b)) = self.constant {
+            let step = match operation {
+                SAOperation::Addition => {
+                    right -= b;
+                    format!("Subtract {b} from both sides")
+                }
+                _ => {
+                    right += b;
+                    format!("Add {b} to both sides")
+                }
+            };
+
+            steps.push(format!("{step}: {0} = {right}", self.x_term()));
+        }
+
+        if let Some((operation, a)) = self.coefficient {
+            let step = match operation {
+                SAOperation::Multiplication => format!("Divide both sides by {a}"),
+                _ => format!("Multiply both sides by {a}"),
+            };
+
+            steps.push(format!("{step}: x = {0}", self.solution));
+        }
+
+        steps
+    }
+}
+
+impl SARender for SAEquationProblem {
+    fn tag(&self) -> String {
+        "Equation".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!(
+            "Problem L{0}: Solve for x: {1}\n",
+            self.number,
+            self.equation_string()
+        )
+    }
+
+    fn instructor_string(&self) -> String {
+        let mut output = format!(
+            "Problem L{0}: Solve for x: {1} Answer: x = {2}\n",
+            self.number,
+            self.equation_string(),
+            self.solution
+        );
+
+        for step in self.inverse_steps() {
+            output.push_str(&format!("    {step}\n"));
+        }
+
+        output
+    }
+}
+
+pub fn sa_generate_equations(options: &SAEquationOptions) -> Vec<SAEquationProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAEquationProblem> = Vec::new();
+    let floor = if options.allow_negatives {
+        options.solution_floor
+    } else {
+        options.solution_floor.max(0)
+    };
+    let ceiling = options.solution_ceiling;
+    let coefficient_operations: Vec<SAOperation> = options
+        .operations
+        .iter()
+        .copied()
+        .filter(|o| matches!(o, SAOperation::Multiplication | SAOperation::Division))
+        .collect();
+    let constant_operations: Vec<SAOperation> = options
+        .operations
+        .iter()
+        .copied()
+        .filter(|o| matches!(o, SAOperation::Addition | SAOperation::Subtraction))
+        .collect();
+
+    if ceiling < floor
+        || options.operations.is_empty()
+        || (options.steps == SAEquationSteps::TwoStep
+            && (coefficient_operations.is_empty() || constant_operations.is_empty()))
+    {
+        return generated_package;
+    }
+
+    let solutions = Uniform::from(floor..=ceiling);
+    let coefficients = Uniform::from(2..=12);
+    let constants = Uniform::from(1..=20);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let solution = solutions.sample(&mut rng);
+        let (coefficient_operation, constant_operation) = match options.steps {
+            SAEquationSteps::TwoStep => (
+                coefficient_operations.choose(&mut rng).copied(),
+                constant_operations.choose(&mut rng).copied(),
+            ),
+            SAEquationSteps::OneStep => {
+                let operation = *options.operations.choose(&mut rng).unwrap();
+
+                if coefficient_operations.contains(&operation) {
+                    (Some(operation), None)
+                } else {
+                    (None, Some(operation))
+                }
+            }
+        };
+
+        let coefficient = match coefficient_operation {
+            Some(operation) => {
+                let mut a = coefficients.sample(&mut rng);
+
+                //Negative coefficients read fine as '-3x', but 'x/-3' just looks like a typo.
+                if operation == SAOperation::Multiplication && options.allow_negatives && rng.gen()
+                {
+                    a = -a;
+                }
+
+                if operation == SAOperation::Division && solution % a != 0 {
+                    continue;
+                }
+
+                Some((operation, a))
+            }
+            None => None,
+        };
+        let constant = constant_operation.map(|operation| (operation, constants.sample(&mut rng)));
+
+        let mut right = solution;
+
+        if let Some((operation, a)) = coefficient {
+            right = operation.calculate(right, a);
+        }
+
+        if let Some((operation, b)) = constant {
+            right = operation.calculate(right, b);
+        }
+
+        if (right < 0 && !options.allow_negatives)
+            || generated_package
+                .iter()
+                .any(|p| p.coefficient == coefficient && p.constant == constant && p.right == right)
+        {
+            continue;
+        }
+
+        generated_package.push(SAEquationProblem {
+            number: generated_package.len() as i32 + 1,
+            solution,
+            coefficient,
+            constant,
+            right,
+        });
+    }
+
+    generated_package
+}
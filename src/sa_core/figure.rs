@@ -0,0 +1,340 @@
+use super::{sa_svg_document, sa_svg_text, SAOperation, SAProblem};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAFigure {
+    None,
+    ///A number line with the start point and the jump to the answer, for addition and
+    ///subtraction.
+    NumberLine,
+    ///constant1 rows of constant2 dots, for multiplication by numbers up to 12.
+    DotArray,
+    ///A box split into place-value parts of each constant, e.g. 23 x 4 as 20 x 4 and 3 x 4.
+    AreaModel,
+}
+
+///Dot arrays stop being countable past 12 x 12.
+const SA_DOT_ARRAY_MAX: i32 = 12;
+
+impl SAFigure {
+    pub fn new() -> Self {
+        SAFigure::None
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAFigure::None => "None".to_owned(),
+            SAFigure::NumberLine => "Number Line".to_owned(),
+            SAFigure::DotArray => "Dot Array".to_owned(),
+            SAFigure::AreaModel => "Area Model".to_owned(),
+        }
+    }
+
+    ///Whether the figure can be drawn for problems of the given operation.
+    pub fn supports(&self, operation: SAOperation) -> bool {
+        match self {
+            SAFigure::None => true,
+            SAFigure::NumberLine => {
+                matches!(operation, SAOperation::Addition | SAOperation::Subtraction)
+            }
+            SAFigure::DotArray | SAFigure::AreaModel => operation == SAOperation::Multiplication,
+        }
+    }
+}
+
+impl Default for SAFigure {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///The smallest 1, 2 or 5 times a power of ten that splits the span into at most 20 ticks.
+fn sa_tick_step(span: i32) -> i32 {
+    let mut power = 1;
+
+    loop {
+        for factor in [1, 2, 5] {
+            if span <= 20 * factor * power {
+                return factor * power;
+            }
+        }
+
+        power *= 10;
+    }
+}
+
+///A positive number split into its non-zero place-value parts, largest first, e.g. 203 -> [200, 3].
+fn sa_place_value_parts(n: i32) -> Vec<i32> {
+    let mut parts = Vec::new();
+    let mut place = 1;
+    let mut remaining = n;
+
+    while remaining > 0 {
+        if remaining % 10 != 0 {
+            parts.push(remaining % 10 * place);
+        }
+
+        remaining /= 10;
+        place *= 10;
+    }
+
+    parts.reverse();
+    parts
+}
+
+impl SAProblem {
+    ///SAProblem::figure_svg() - the problem's figure, if it has one that suits its operation and
+    ///constants. Dot arrays and area models need both constants to be positive, and dot arrays
+    ///need them to be no more than 12.
+    pub fn figure_svg(&self, instructor: bool) -> Option<String> {
+        if !self.figure.supports(self.operation) {
+            return None;
+        }
+
+        let positive = self.constant1 > 0 && self.constant2 > 0;
+
+        match self.figure {
+            SAFigure::None => None,
+            SAFigure::NumberLine => Some(self.number_line_svg(instructor)),
+            SAFigure::DotArray
+                if positive && self.constant1.max(self.constant2) <= SA_DOT_ARRAY_MAX =>
+            {
+                Some(self.dot_array_svg(instructor))
+            }
+            SAFigure::AreaModel if positive => Some(self.area_model_svg(instructor)),
+            _ => None,
+        }
+    }
+
+    ///SAProblem::number_line_svg() - a number line running from a little below to a little above
+    ///constant1 and the result. The student's copy is left blank; the instructor's marks the
+    ///start point and draws the jump to the answer as an arrow labeled with the change.
+    pub fn number_line_svg(&self, instructor: bool) -> String {
+        let (width, height) = (600, if instructor { 160 } else { 90 });
+        let margin = 40.0;
+        let axis_y = height as f64 - 45.0;
+        let (low, high) = (
+            self.constant1.min(self.result),
+            self.constant1.max(self.result),
+        );
+        let step = sa_tick_step((high - low).max(1));
+        let first = low.div_euclid(step) * step - step;
+        let last = (high + step - 1).div_euclid(step) * step + step;
+        let scale = (width as f64 - 2.0 * margin) / (last - first) as f64;
+        let x_of = |n: i32| margin + (n - first) as f64 * scale;
+        let mut body = String::new();
+
+        body.push_str(&format!(
+            "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"9\" refY=\"5\" markerWidth=\"5\" markerHeight=\"5\" orient=\"auto-start-reverse\"><path d=\"M 0 0 L 10 5 L 0 10 z\"/></marker></defs>\n<line x1=\"{0:.1}\" y1=\"{axis_y:.1}\" x2=\"{1:.1}\" y2=\"{axis_y:.1}\" stroke=\"black\" stroke-width=\"2\" marker-start=\"url(#arrow)\" marker-end=\"url(#arrow)\"/>\n",
+            margin - 20.0,
+            width as f64 - margin + 20.0
+        ));
+
+        for tick in (first..=last).step_by(step as usize) {
+            let x = x_of(tick);
+
+            body.push_str(&format!(
+                "<line x1=\"{x:.1}\" y1=\"{0:.1}\" x2=\"{x:.1}\" y2=\"{1:.1}\" stroke=\"black\" stroke-width=\"2\"/>\n",
+                axis_y - 6.0,
+                axis_y + 6.0
+            ));
+            body.push_str(&sa_svg_text(x, axis_y + 20.0, "middle", &tick.to_string()));
+        }
+
+        if instructor {
+            let (start, end) = (x_of(self.constant1), x_of(self.result));
+            let change = self.result - self.constant1;
+            let apex = axis_y - 90.0;
+
+            body.push_str(&format!(
+                "<circle cx=\"{start:.1}\" cy=\"{axis_y:.1}\" r=\"5\" fill=\"#1f5fa8\"/>\n<circle cx=\"{end:.1}\" cy=\"{axis_y:.1}\" r=\"5\" fill=\"white\" stroke=\"#1f5fa8\" stroke-width=\"2\"/>\n"
+            ));
+
+            if change != 0 {
+                //A quadratic curve only rises halfway to its control point, so the label sits
+                //just above the middle of the way up to `apex`.
+                body.push_str(&format!(
+                    "<path d=\"M {start:.1} {0:.1} Q {1:.1} {apex:.1} {end:.1} {0:.1}\" fill=\"none\" stroke=\"#1f5fa8\" stroke-width=\"2\" marker-end=\"url(#arrow)\"/>\n",
+                    axis_y - 6.0,
+                    (start + end) / 2.0
+                ));
+                body.push_str(&sa_svg_text(
+                    (start + end) / 2.0,
+                    (axis_y + apex) / 2.0 - 14.0,
+                    "middle",
+                    &format!("{change:+}"),
+                ));
+            }
+
+            body.push_str(&sa_svg_text(
+                width as f64 / 2.0,
+                18.0,
+                "middle",
+                &format!(
+                    "Start at {0}, then {1} to land on {2}",
+                    self.constant1,
+                    if change < 0 {
+                        format!("move {0} left", -change)
+                    } else {
+                        format!("move {change} right")
+                    },
+                    self.result
+                ),
+            ));
+        }
+
+        sa_svg_document(width, height, &body)
+    }
+
+    ///SAProblem::dot_array_svg() - constant1 rows of constant2 dots. The instructor's copy adds
+    ///the total underneath.
+    pub fn dot_array_svg(&self, instructor: bool) -> String {
+        let spacing = 24.0;
+        let margin = 40.0;
+        let (rows, columns) = (self.constant1, self.constant2);
+        let width = (2.0 * margin + (columns - 1) as f64 * spacing).max(260.0);
+        let height = 2.0 * margin + (rows - 1) as f64 * spacing + 40.0;
+        let left = (width - (columns - 1) as f64 * spacing) / 2.0;
+        let mut body = String::new();
+
+        body.push_str(&sa_svg_text(
+            width / 2.0,
+            margin / 2.0,
+            "middle",
+            &format!("{rows} rows of {columns}"),
+        ));
+
+        for row in 0..rows {
+            for column in 0..columns {
+                body.push_str(&format!(
+                    "<circle cx=\"{0:.1}\" cy=\"{1:.1}\" r=\"7\" fill=\"#1f5fa8\"/>\n",
+                    left + column as f64 * spacing,
+                    margin + row as f64 * spacing
+                ));
+            }
+        }
+
+        if instructor {
+            body.push_str(&sa_svg_text(
+                width / 2.0,
+                height - margin / 2.0,
+                "middle",
+                &format!("{rows} \u{D7} {columns} = {0}", self.result),
+            ));
+        }
+
+        sa_svg_document(width as i32, height as i32, &body)
+    }
+
+    ///SAProblem::area_model_svg() - a box with a column for each place-value part of constant1
+    ///and a row for each part of constant2, both labeled along the outside. Parts get space in
+    ///proportion to their size, but never so little that their label won't fit. The instructor's
+    ///copy fills in each partial product and writes out the sum underneath, e.g.
+    ///23 x 4 = (20 x 4) + (3 x 4) = 80 + 12 = 92.
+    pub fn area_model_svg(&self, instructor: bool) -> String {
+        let (columns, rows) = (
+            sa_place_value_parts(self.constant1),
+            sa_place_value_parts(self.constant2),
+        );
+        let pairs: Vec<(i32, i32)> = rows
+            .iter()
+            .flat_map(|row| columns.iter().map(move |column| (*column, *row)))
+            .collect();
+        let factors: Vec<String> = pairs
+            .iter()
+            .map(|(column, row)| format!("({column} \u{D7} {row})"))
+            .collect();
+        let products: Vec<String> = pairs
+            .iter()
+            .map(|(column, row)| (column * row).to_string())
+            .collect();
+        let breakdown = [
+            format!(
+                "{0} \u{D7} {1} = {2}",
+                self.constant1,
+                self.constant2,
+                factors.join(" + ")
+            ),
+            format!("= {0} = {1}", products.join(" + "), self.result),
+        ];
+        let margin = 60.0;
+        let (box_width, box_height) = (420.0, 240.0);
+        //Each part's share of the box, with a floor so small parts still have room for a label.
+        let sizes = |parts: &[i32], length: f64, minimum: f64| -> Vec<f64> {
+            let total: i32 = parts.iter().sum();
+            let raw: Vec<f64> = parts
+                .iter()
+                .map(|p| (*p as f64 / total as f64 * length).max(minimum))
+                .collect();
+            let scale = length / raw.iter().sum::<f64>();
+
+            raw.iter().map(|r| r * scale).collect()
+        };
+        let widths = sizes(&columns, box_width, 70.0);
+        let heights = sizes(&rows, box_height, 40.0);
+        //Roughly 8 pixels a character at the document's font size.
+        let text_width = breakdown
+            .iter()
+            .map(|l| l.chars().count() as f64 * 8.0 + 40.0)
+            .fold(0.0, f64::max);
+        let width = (box_width + 2.0 * margin).max(text_width);
+        let height = box_height + 2.0 * margin + 50.0;
+        let left = (width - box_width) / 2.0;
+        let mut body = String::new();
+        let mut y = margin;
+
+        for (row, row_height) in rows.iter().zip(heights.iter()) {
+            let mut x = left;
+
+            body.push_str(&sa_svg_text(
+                left - 10.0,
+                y + row_height / 2.0,
+                "end",
+                &row.to_string(),
+            ));
+
+            for (column, column_width) in columns.iter().zip(widths.iter()) {
+                body.push_str(&format!(
+                    "<rect x=\"{x:.1}\" y=\"{y:.1}\" width=\"{column_width:.1}\" height=\"{row_height:.1}\" fill=\"#eef4fb\" stroke=\"black\" stroke-width=\"2\"/>\n"
+                ));
+
+                if instructor {
+                    body.push_str(&sa_svg_text(
+                        x + column_width / 2.0,
+                        y + row_height / 2.0,
+                        "middle",
+                        &(column * row).to_string(),
+                    ));
+                }
+
+                x += column_width;
+            }
+
+            y += row_height;
+        }
+
+        let mut x = left;
+
+        for (column, column_width) in columns.iter().zip(widths.iter()) {
+            body.push_str(&sa_svg_text(
+                x + column_width / 2.0,
+                margin - 14.0,
+                "middle",
+                &column.to_string(),
+            ));
+            x += column_width;
+        }
+
+        if instructor {
+            for (index, line) in breakdown.iter().enumerate() {
+                body.push_str(&sa_svg_text(
+                    width / 2.0,
+                    height - 56.0 + index as f64 * 22.0,
+                    "middle",
+                    line,
+                ));
+            }
+        }
+
+        sa_svg_document(width as i32, height as i32, &body)
+    }
+}
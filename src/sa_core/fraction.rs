@@ -0,0 +1,104 @@
+use super::sa_gcd;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+///An exact rational number, always kept in lowest terms with the sign on the numerator.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SAFraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl SAFraction {
+    ///SAFraction::new() - builds and simplifies numerator/denominator. A zero denominator is
+    ///treated as 0/1 rather than panicking, so callers should check for it themselves.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        if denominator == 0 {
+            return SAFraction::from(0);
+        }
+
+        let divisor = sa_gcd(numerator, denominator).max(1) * denominator.signum();
+
+        SAFraction {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn from(n: i64) -> Self {
+        SAFraction {
+            numerator: n,
+            denominator: 1,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    ///e.g. '3/4', '-1/2', or '5' for whole numbers.
+    pub fn as_string(&self) -> String {
+        if self.is_integer() {
+            self.numerator.to_string()
+        } else {
+            format!("{0}/{1}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Default for SAFraction {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl Add for SAFraction {
+    type Output = SAFraction;
+
+    fn add(self, other: SAFraction) -> SAFraction {
+        SAFraction::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Sub for SAFraction {
+    type Output = SAFraction;
+
+    fn sub(self, other: SAFraction) -> SAFraction {
+        self + -other
+    }
+}
+
+impl Mul for SAFraction {
+    type Output = SAFraction;
+
+    fn mul(self, other: SAFraction) -> SAFraction {
+        SAFraction::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Div for SAFraction {
+    type Output = SAFraction;
+
+    fn div(self, other: SAFraction) -> SAFraction {
+        SAFraction::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
+impl Neg for SAFraction {
+    type Output = SAFraction;
+
+    fn neg(self) -> SAFraction {
+        SAFraction {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAShapeKind {
+    Rectangle,
+    Square,
+    RightTriangle,
+    LShape,
+}
+
+///A figure and its side lengths.
+#[derive(Copy, Clone, PartialEq)]
+pub enum SAShape {
+    Rectangle {
+        width: i32,
+        height: i32,
+    },
+    Square {
+        side: i32,
+    },
+    ///Right angle at the bottom left; `base` runs along the bottom and `height` up the left side.
+    RightTriangle {
+        base: i32,
+        height: i32,
+        hypotenuse: i32,
+    },
+    ///A `width` by `height` rectangle with a `cut_width` by `cut_height` notch taken out of its
+    ///top right corner.
+    LShape {
+        width: i32,
+        height: i32,
+        cut_width: i32,
+        cut_height: i32,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAGeometryMeasure {
+    Perimeter,
+    Area,
+}
+
+///Options for perimeter and area problems. Every labeled side length falls between the floor and
+///ceiling, and `shapes` lists which figures may be used.
+pub struct SAGeometryOptions {
+    pub problems_num: i32,
+    pub problems_floor: i32,
+    pub problems_ceiling: i32,
+    pub measure: SAGeometryMeasure,
+    pub shapes: Vec<SAShapeKind>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAGeometryProblem {
+    pub number: i32,
+    pub shape: SAShape,
+    pub measure: SAGeometryMeasure,
+}
+
+///Pythagorean triples, smallest first, for right triangles with whole-number sides.
+const SA_PYTHAGOREAN_TRIPLES: [(i32, i32, i32); 6] = [
+    (3, 4, 5),
+    (5, 12, 13),
+    (8, 15, 17),
+    (7, 24, 25),
+    (20, 21, 29),
+    (9, 40, 41),
+];
+
+const SA_UNIT: &str = "cm";
+
+impl SAShapeKind {
+    pub fn as_string(&self) -> String {
+        match self {
+            SAShapeKind::Rectangle => "Rectangle".to_owned(),
+            SAShapeKind::Square => "Square".to_owned(),
+            SAShapeKind::RightTriangle => "Right Triangle".to_owned(),
+            SAShapeKind::LShape => "L-Shape".to_owned(),
+        }
+    }
+}
+
+impl SAGeometryMeasure {
+    pub fn new() -> Self {
+        SAGeometryMeasure::Perimeter
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAGeometryMeasure::Perimeter => "Perimeter".to_owned(),
+            SAGeometryMeasure::Area => "Area".to_owned(),
+        }
+    }
+}
+
+impl Default for SAGeometryMeasure {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAGeometryOptions {
+    pub fn new() -> Self {
+        SAGeometryOptions {
+            problems_num: 0,
+            problems_floor: 0,
+            problems_ceiling: 0,
+            measure: SAGeometryMeasure::new(),
+            shapes: vec![
+                SAShapeKind::Rectangle,
+                SAShapeKind::Square,
+                SAShapeKind::RightTriangle,
+                SAShapeKind::LShape,
+            ],
+        }
+    }
+}
+
+impl Default for SAGeometryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAShape {
+    pub fn perimeter(&self) -> i32 {
+        match *self {
+            SAShape::Rectangle { width, height } => 2 * (width + height),
+            SAShape::Square { side } => 4 * side,
+            SAShape::RightTriangle {
+                base,
+                height,
+                hypotenuse,
+            } => base + height + hypotenuse,
+            //The notch moves two sides inward without changing their total length.
+            SAShape::LShape { width, height, .. } => 2 * (width + height),
+        }
+    }
+
+    pub fn area(&self) -> i32 {
+        match *self {
+            SAShape::Rectangle { width, height } => width * height,
+            SAShape::Square { side } => side * side,
+            //Legs from a Pythagorean triple always have an even product, so this stays exact.
+            SAShape::RightTriangle { base, height, .. } => base * height / 2,
+            SAShape::LShape {
+                width,
+                height,
+                cut_width,
+                cut_height,
+            } => width * height - cut_width * cut_height,
+        }
+    }
+
+    fn description(&self) -> String {
+        match *self {
+            SAShape::Rectangle { width, height } => {
+                format!("a rectangle {width} {SA_UNIT} long and {height} {SA_UNIT} wide")
+            }
+            SAShape::Square { side } => format!("a square with {side} {SA_UNIT} sides"),
+            SAShape::RightTriangle {
+                base,
+                height,
+                hypotenuse,
+            } => format!(
+                "a right triangle with sides {base} {SA_UNIT}, {height} {SA_UNIT} and {hypotenuse} {SA_UNIT}"
+            ),
+            SAShape::LShape { .. } => {
+                let sides: Vec<String> = self
+                    .outline()
+                    .iter()
+                    .filter_map(|(_, label)| *label)
+                    .map(|side| format!("{side} {SA_UNIT}"))
+                    .collect();
+
+                format!(
+                    "an L-shaped figure with sides {0} and {1}, clockwise from the top left",
+                    sides[..sides.len() - 1].join(", "),
+                    sides[sides.len() - 1]
+                )
+            }
+        }
+    }
+
+    ///Corners in drawing order (clockwise on screen, y pointing down), in side-length units,
+    ///each paired with the label for the side running to the next corner.
+    fn outline(&self) -> Vec<((i32, i32), Option<i32>)> {
+        match *self {
+            SAShape::Rectangle { width, height } => vec![
+                ((0, 0), Some(width)),
+                ((width, 0), Some(height)),
+                ((width, height), None),
+                ((0, height), None),
+            ],
+            SAShape::Square { side } => vec![
+                ((0, 0), Some(side)),
+                ((side, 0), None),
+                ((side, side), None),
+                ((0, side), None),
+            ],
+            SAShape::RightTriangle {
+                base,
+                height,
+                hypotenuse,
+            } => vec![
+                ((0, 0), Some(hypotenuse)),
+                ((base, height), Some(base)),
+                ((0, height), Some(height)),
+            ],
+            SAShape::LShape {
+                width,
+                height,
+                cut_width,
+                cut_height,
+            } => vec![
+                ((0, 0), Some(width - cut_width)),
+                ((width - cut_width, 0), Some(cut_height)),
+                ((width - cut_width, cut_height), Some(cut_width)),
+                ((width, cut_height), Some(height - cut_height)),
+                ((width, height), Some(width)),
+                ((0, height), Some(height)),
+            ],
+        }
+    }
+}
+
+///sa_svg_document() - wraps SVG elements in a document of the given size, on a white background.
+pub fn sa_svg_document(width: i32, height: i32, body: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\" font-size=\"14\">\n<rect width=\"{width}\" height=\"{height}\" fill=\"white\"/>\n{body}</svg>\n"
+    )
+}
+
+///sa_svg_text() - a text element, vertically centered on the point. `anchor` is the SVG
+///text-anchor: 'start', 'middle' or 'end'.
+pub fn sa_svg_text(x: f64, y: f64, anchor: &str, text: &str) -> String {
+    format!(
+        "<text x=\"{x:.1}\" y=\"{y:.1}\" text-anchor=\"{anchor}\" dominant-baseline=\"middle\">{text}</text>\n"
+    )
+}
+
+impl SAGeometryProblem {
+    fn question(&self) -> String {
+        format!(
+            "Find the {0} of {1}. (See Figure {2})",
+            self.measure.as_string().to_lowercase(),
+            self.shape.description(),
+            self.number
+        )
+    }
+
+    pub fn answer(&self) -> String {
+        match self.measure {
+            SAGeometryMeasure::Perimeter => format!("{0} {SA_UNIT}", self.shape.perimeter()),
+            SAGeometryMeasure::Area => format!("{0} {SA_UNIT}\u{B2}", self.shape.area()),
+        }
+    }
+}
+
+impl SARender for SAGeometryProblem {
+    fn tag(&self) -> String {
+        self.measure.as_string()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem G{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem G{0}: {1} Answer: {2}\n",
+            self.number,
+            self.question(),
+            self.answer()
+        )
+    }
+
+    ///The figure with every side length labeled outside its edge, scaled to fit. The
+    ///instructor's copy adds the answer underneath.
+    fn diagram(&self, instructor: bool) -> Option<String> {
+        let (width, height) = (320, 260);
+        let margin = 40.0;
+        let outline = self.shape.outline();
+        let extent_x = outline
+            .iter()
+            .map(|((x, _), _)| *x)
+            .max()
+            .unwrap_or(1)
+            .max(1) as f64;
+        let extent_y = outline
+            .iter()
+            .map(|((_, y), _)| *y)
+            .max()
+            .unwrap_or(1)
+            .max(1) as f64;
+        let scale = ((width as f64 - 2.0 * margin) / extent_x)
+            .min((height as f64 - 2.0 * margin - 30.0) / extent_y);
+        let point = |(x, y): (i32, i32)| (margin + x as f64 * scale, margin + y as f64 * scale);
+        let mut body = String::new();
+
+        let points: Vec<String> = outline
+            .iter()
+            .map(|(corner, _)| {
+                let (x, y) = point(*corner);
+                format!("{x:.1},{y:.1}")
+            })
+            .collect();
+
+        body.push_str(&format!(
+            "<polygon points=\"{0}\" fill=\"#eef4fb\" stroke=\"black\" stroke-width=\"2\"/>\n",
+            points.join(" ")
+        ));
+
+        for (index, (corner, label)) in outline.iter().enumerate() {
+            let Some(length) = label else {
+                continue;
+            };
+
+            let (x1, y1) = point(*corner);
+            let (x2, y2) = point(outline[(index + 1) % outline.len()].0);
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length_on_page = (dx * dx + dy * dy).sqrt().max(1.0);
+            //Going clockwise on screen, (dy, -dx) always points out of the figure.
+            let (nx, ny) = (dy / length_on_page, -dx / length_on_page);
+
+            //Labels beside upright edges are anchored at the edge so they grow away from it.
+            let anchor = if nx > 0.7 {
+                "start"
+            } else if nx < -0.7 {
+                "end"
+            } else {
+                "middle"
+            };
+            let offset = if anchor == "middle" { 14.0 } else { 6.0 };
+
+            body.push_str(&sa_svg_text(
+                (x1 + x2) / 2.0 + nx * offset,
+                (y1 + y2) / 2.0 + ny * offset,
+                anchor,
+                &format!("{length} {SA_UNIT}"),
+            ));
+        }
+
+        if instructor {
+            body.push_str(&sa_svg_text(
+                width as f64 / 2.0,
+                height as f64 - 16.0,
+                "middle",
+                &format!("{0} = {1}", self.measure.as_string(), self.answer()),
+            ));
+        }
+
+        Some(sa_svg_document(width, height, &body))
+    }
+}
+
+pub fn sa_generate_geometry(options: &SAGeometryOptions) -> Vec<SAGeometryProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAGeometryProblem> = Vec::new();
+    let floor = options.problems_floor.max(1);
+    let ceiling = options.problems_ceiling;
+
+    if ceiling < floor || options.shapes.is_empty() {
+        return generated_package;
+    }
+
+    let sides = Uniform::from(floor..=ceiling);
+    let within = |n: i32| (floor..=ceiling).contains(&n);
+    //Kinds the side range can't build at all are dropped up front, since the sticky kind below
+    //would otherwise spend every remaining attempt on them.
+    let shapes: Vec<SAShapeKind> = options
+        .shapes
+        .iter()
+        .copied()
+        .filter(|kind| match kind {
+            SAShapeKind::Rectangle => floor < ceiling,
+            SAShapeKind::Square => true,
+            SAShapeKind::RightTriangle => SA_PYTHAGOREAN_TRIPLES
+                .iter()
+                .any(|(a, b, c)| (1..=ceiling / c).any(|k| within(a * k) && within(b * k))),
+            //The smallest L-shape is a floor-wide notch out of a figure twice the floor wide.
+            SAShapeKind::LShape => floor * 2 <= ceiling,
+        })
+        .collect();
+
+    if shapes.is_empty() {
+        return generated_package;
+    }
+
+    let mut kind = *shapes.choose(&mut rng).unwrap();
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        //The shape kind sticks until a problem is made with it, otherwise the kinds that are
+        //easy to fit (squares) would crowd out the ones that take a few tries (triangles).
+        let shape = match kind {
+            SAShapeKind::Rectangle => {
+                let (width, height) = (sides.sample(&mut rng), sides.sample(&mut rng));
+
+                if width == height {
+                    continue;
+                }
+
+                SAShape::Rectangle { width, height }
+            }
+            SAShapeKind::Square => SAShape::Square {
+                side: sides.sample(&mut rng),
+            },
+            SAShapeKind::RightTriangle => {
+                let (a, b, c) = *SA_PYTHAGOREAN_TRIPLES.choose(&mut rng).unwrap();
+                let k = rng.gen_range(1..=(ceiling / c).max(1));
+                let (base, height) = if rng.gen() { (a, b) } else { (b, a) };
+
+                if !within(base * k) || !within(height * k) || !within(c * k) {
+                    continue;
+                }
+
+                SAShape::RightTriangle {
+                    base: base * k,
+                    height: height * k,
+                    hypotenuse: c * k,
+                }
+            }
+            SAShapeKind::LShape => {
+                let (width, height) = (sides.sample(&mut rng), sides.sample(&mut rng));
+                let (cut_width, cut_height) = (sides.sample(&mut rng), sides.sample(&mut rng));
+
+                //A notch under a quarter of the figure is too small to label legibly.
+                if !within(width - cut_width)
+                    || !within(height - cut_height)
+                    || cut_width * 4 < width
+                    || cut_height * 4 < height
+                {
+                    continue;
+                }
+
+                SAShape::LShape {
+                    width,
+                    height,
+                    cut_width,
+                    cut_height,
+                }
+            }
+        };
+
+        //A repeat may mean the kind has run out of new shapes, so give another kind a turn.
+        if generated_package.iter().any(|p| p.shape == shape) {
+            kind = *shapes.choose(&mut rng).unwrap();
+            continue;
+        }
+
+        generated_package.push(SAGeometryProblem {
+            number: generated_package.len() as i32 + 1,
+            shape,
+            measure: options.measure,
+        });
+        kind = *shapes.choose(&mut rng).unwrap();
+    }
+
+    generated_package
+}
@@ -0,0 +1,257 @@
+use super::{sa_long_division_steps, SAExportOptions, SAOperation, SAProblem, SARender};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SALayout {
+    Horizontal,
+    Vertical,
+}
+
+impl SALayout {
+    pub fn new() -> Self {
+        SALayout::Horizontal
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SALayout::Horizontal => "Horizontal".to_owned(),
+            SALayout::Vertical => "Vertical".to_owned(),
+        }
+    }
+}
+
+impl Default for SALayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAProblem {
+    ///SAProblem::vertical_lines() - stacked, right-aligned rendering of the problem.
+    ///
+    ///The operator sits in its own column to the left of the second constant, and multi-digit
+    ///multiplication gets a line per partial product between the two answer rules. Division is
+    ///laid out with a long division bracket instead, see SAProblem::long_division_lines().
+    pub fn vertical_lines(&self, show_answer: bool) -> Option<Vec<String>> {
+        if self.operation == SAOperation::Division {
+            return self.long_division_lines(show_answer);
+        }
+
+        let operation_char = self.operation.as_string().chars().nth(0).unwrap();
+        let partials = self.partial_products();
+        let width = [self.constant1, self.constant2, self.result]
+            .iter()
+            .chain(partials.iter())
+            .map(|n| n.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let rule = "-".repeat(width + 2);
+        let answer_line = |n: i32| {
+            if show_answer {
+                format!("  {n:>width$}")
+            } else {
+                " ".repeat(width + 2)
+            }
+        };
+
+        let mut lines = vec![
+            format!("{0}{1}.", operation_char, self.number),
+            format!("  {0:>width$}", self.constant1),
+            format!(
+                "{0} {1:>width$}",
+                self.operation.as_symbol(),
+                self.constant2
+            ),
+            rule.clone(),
+        ];
+
+        if !partials.is_empty() {
+            for partial in &partials {
+                lines.push(answer_line(*partial));
+            }
+
+            lines.push(rule);
+        }
+
+        lines.push(answer_line(self.result));
+
+        if let Some(choices) = self.choices {
+            lines.extend(choices.labels());
+
+            if show_answer {
+                lines.push(format!("Answer: {0}", choices.correct_letter()));
+            }
+        }
+
+        Some(lines)
+    }
+
+    ///SAProblem::long_division_lines() - the division bracket under a 'D3.' heading, followed by
+    ///any choices, see SAProblem::long_division_bracket().
+    pub fn long_division_lines(&self, show_answer: bool) -> Option<Vec<String>> {
+        let operation_char = self.operation.as_string().chars().nth(0).unwrap();
+        let mut lines = vec![format!("{0}{1}.", operation_char, self.number)];
+
+        lines.extend(self.long_division_bracket(show_answer)?);
+
+        if let Some(choices) = self.choices {
+            lines.extend(choices.labels());
+
+            if show_answer {
+                lines.push(format!("Answer: {0}", choices.correct_letter()));
+            }
+        }
+
+        Some(lines)
+    }
+
+    ///SAProblem::long_division_bracket() - the division bracket, with the quotient (and remainder)
+    ///on top and every multiply, subtract and bring-down worked below it when showing the answer.
+    ///
+    ///Each step lines up under the dividend digit it was brought down to. Only non-negative
+    ///dividends and positive divisors have a long division form; anything else returns None.
+    pub fn long_division_bracket(&self, show_answer: bool) -> Option<Vec<String>> {
+        let (dividend, divisor) = (self.constant1 as i64, self.constant2 as i64);
+
+        if self.operation != SAOperation::Division || dividend < 0 || divisor <= 0 {
+            return None;
+        }
+
+        let digits = dividend.to_string();
+        let bracket = format!("{divisor} ) ");
+        let offset = bracket.chars().count();
+        let steps = sa_long_division_steps(dividend, divisor);
+        //Right-aligns a number so its last digit sits under the given dividend digit.
+        let at = |n: i64, position: usize| format!("{n:>0$}", offset + position + 1);
+        let rule_at = |width: usize, position: usize| {
+            format!("{0:>1$}", "-".repeat(width), offset + position + 1)
+        };
+
+        let mut lines = Vec::new();
+
+        if show_answer {
+            let quotient = dividend / divisor;
+            let remainder = dividend % divisor;
+            let first = steps.first().map(|s| s.position).unwrap_or(0);
+            let mut quotient_line = format!("{0}{1}", " ".repeat(offset + first), quotient);
+
+            if remainder > 0 {
+                quotient_line.push_str(&format!(" R{remainder}"));
+            }
+
+            lines.push(quotient_line);
+        }
+
+        lines.push(format!(
+            "{0}{1}",
+            " ".repeat(offset - 2),
+            "_".repeat(digits.len() + 2)
+        ));
+        lines.push(format!("{bracket}{digits}"));
+
+        if !show_answer {
+            return Some(lines);
+        }
+
+        for (index, step) in steps.iter().enumerate() {
+            if index > 0 {
+                lines.push(at(step.partial, step.position));
+            }
+
+            let width = step.partial.to_string().len();
+            lines.push(at(step.product, step.position));
+            lines.push(rule_at(width, step.position));
+        }
+
+        if let Some(last) = steps.last() {
+            lines.push(at(last.difference, last.position));
+        }
+
+        Some(lines)
+    }
+
+    ///Partial products for multiplication by a multi-digit, non-negative second constant. Each is
+    ///already shifted by its place value.
+    pub fn partial_products(&self) -> Vec<i32> {
+        if self.operation != SAOperation::Multiplication || self.constant2 < 10 {
+            return Vec::new();
+        }
+
+        let mut partials = Vec::new();
+        let mut remaining = self.constant2;
+        let mut place = 1;
+
+        while remaining > 0 {
+            partials.push(self.constant1 * (remaining % 10) * place);
+            remaining /= 10;
+            place *= 10;
+        }
+
+        partials
+    }
+}
+
+///sa_layout_grid() - arranges blocks of lines into a grid with the given number of columns.
+///
+///Each column is as wide as its widest block, and each row of the grid is separated by a blank line.
+pub fn sa_layout_grid(blocks: &[Vec<String>], columns: usize) -> String {
+    let columns = columns.max(1);
+    let gap = "    ";
+    let mut widths = vec![0usize; columns];
+    let mut output = String::new();
+
+    for (index, block) in blocks.iter().enumerate() {
+        let widest = block.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        widths[index % columns] = widths[index % columns].max(widest);
+    }
+
+    for row in blocks.chunks(columns) {
+        let height = row.iter().map(|b| b.len()).max().unwrap_or(0);
+
+        for line_index in 0..height {
+            let mut line = String::new();
+
+            for (column, block) in row.iter().enumerate() {
+                let cell = block.get(line_index).map(|l| l.as_str()).unwrap_or("");
+                line.push_str(&format!("{cell:<0$}", widths[column]));
+                line.push_str(gap);
+            }
+
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+///sa_layout() - renders a package the way it will appear in an exported file.
+///
+///Used by sa_export() itself and anything that wants to preview the output beforehand. Kinds
+///without a vertical rendering fall back to their horizontal line inside the grid.
+pub fn sa_layout<P: SARender>(data: &[P], options: &SAExportOptions, instructor: bool) -> String {
+    let horizontal = |problem: &P| {
+        if instructor {
+            problem.instructor_string()
+        } else {
+            problem.student_string()
+        }
+    };
+
+    match options.layout {
+        SALayout::Horizontal => data.iter().map(horizontal).collect(),
+        SALayout::Vertical => {
+            let blocks: Vec<Vec<String>> = data
+                .iter()
+                .map(|problem| {
+                    problem.vertical_block(instructor).unwrap_or_else(|| {
+                        horizontal(problem).lines().map(|l| l.to_owned()).collect()
+                    })
+                })
+                .collect();
+
+            sa_layout_grid(&blocks, options.columns.max(1) as usize)
+        }
+    }
+}
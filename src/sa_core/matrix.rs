@@ -0,0 +1,517 @@
+use super::{sa_superscript, SAFraction, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAMatrixKind {
+    ///'Find A + B.'
+    Addition,
+    ///'Find A x B.'
+    Multiplication,
+    ///'Find det(A).'
+    Determinant,
+    ///'Find A⁻¹.'
+    Inverse,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAMatrixSize {
+    TwoByTwo,
+    ThreeByThree,
+}
+
+///Options for matrix problems. Every entry of A and B is between the floor and the ceiling, and
+///`kinds` lists which problems may be asked.
+pub struct SAMatrixOptions {
+    pub problems_num: i32,
+    pub entry_floor: i32,
+    pub entry_ceiling: i32,
+    pub size: SAMatrixSize,
+    pub kinds: Vec<SAMatrixKind>,
+    ///Makes about a third of the entries simple fractions (halves, thirds or quarters) rather
+    ///than integers.
+    pub rational_entries: bool,
+}
+
+///A square matrix, row by row. Entries are fractions so that inverses stay exact.
+#[derive(Clone, PartialEq)]
+pub struct SAMatrix {
+    pub rows: Vec<Vec<SAFraction>>,
+}
+
+///A matrix problem on A, and on B as well for addition and multiplication.
+#[derive(Clone, PartialEq)]
+pub struct SAMatrixProblem {
+    pub number: i32,
+    pub kind: SAMatrixKind,
+    pub left: SAMatrix,
+    pub right: Option<SAMatrix>,
+}
+
+///Inverses are only asked for when the determinant's numerator is at most this far from zero,
+///and its denominator no bigger, which keeps the fractions in the answer to small denominators.
+const SA_INVERSE_MAX_DETERMINANT: i64 = 12;
+
+impl SAMatrixKind {
+    pub fn new() -> Self {
+        SAMatrixKind::Addition
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAMatrixKind::Addition => "Addition".to_owned(),
+            SAMatrixKind::Multiplication => "Multiplication".to_owned(),
+            SAMatrixKind::Determinant => "Determinant".to_owned(),
+            SAMatrixKind::Inverse => "Inverse".to_owned(),
+        }
+    }
+}
+
+impl Default for SAMatrixKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMatrixSize {
+    pub fn new() -> Self {
+        SAMatrixSize::TwoByTwo
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAMatrixSize::TwoByTwo => "2x2".to_owned(),
+            SAMatrixSize::ThreeByThree => "3x3".to_owned(),
+        }
+    }
+
+    fn dimension(&self) -> usize {
+        match self {
+            SAMatrixSize::TwoByTwo => 2,
+            SAMatrixSize::ThreeByThree => 3,
+        }
+    }
+}
+
+impl Default for SAMatrixSize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMatrixOptions {
+    pub fn new() -> Self {
+        SAMatrixOptions {
+            problems_num: 0,
+            entry_floor: 0,
+            entry_ceiling: 0,
+            size: SAMatrixSize::new(),
+            kinds: vec![
+                SAMatrixKind::Addition,
+                SAMatrixKind::Multiplication,
+                SAMatrixKind::Determinant,
+                SAMatrixKind::Inverse,
+            ],
+            rational_entries: false,
+        }
+    }
+}
+
+impl Default for SAMatrixOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMatrix {
+    fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn add(&self, other: &SAMatrix) -> SAMatrix {
+        SAMatrix {
+            rows: self
+                .rows
+                .iter()
+                .zip(other.rows.iter())
+                .map(|(a, b)| a.iter().zip(b.iter()).map(|(x, y)| *x + *y).collect())
+                .collect(),
+        }
+    }
+
+    pub fn multiply(&self, other: &SAMatrix) -> SAMatrix {
+        let n = self.dimension();
+
+        SAMatrix {
+            rows: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            (0..n).fold(SAFraction::from(0), |sum, k| {
+                                sum + self.rows[i][k] * other.rows[k][j]
+                            })
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    ///The matrix left after crossing out the given row and column.
+    fn minor(&self, row: usize, column: usize) -> SAMatrix {
+        SAMatrix {
+            rows: self
+                .rows
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != row)
+                .map(|(_, r)| {
+                    r.iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != column)
+                        .map(|(_, x)| *x)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    fn cofactor(&self, row: usize, column: usize) -> SAFraction {
+        let minor = self.minor(row, column).determinant();
+
+        match (row + column) % 2 {
+            0 => minor,
+            _ => -minor,
+        }
+    }
+
+    ///SAMatrix::determinant() - cofactor expansion along the first row.
+    pub fn determinant(&self) -> SAFraction {
+        match self.dimension() {
+            0 => SAFraction::from(1),
+            1 => self.rows[0][0],
+            n => (0..n).fold(SAFraction::from(0), |sum, j| {
+                sum + self.rows[0][j] * self.cofactor(0, j)
+            }),
+        }
+    }
+
+    ///SAMatrix::inverse() - the adjugate divided by the determinant, or None for a singular
+    ///matrix.
+    pub fn inverse(&self) -> Option<SAMatrix> {
+        let determinant = self.determinant();
+        let n = self.dimension();
+
+        if determinant == SAFraction::from(0) {
+            return None;
+        }
+
+        Some(SAMatrix {
+            rows: (0..n)
+                .map(|i| (0..n).map(|j| self.cofactor(j, i) / determinant).collect())
+                .collect(),
+        })
+    }
+
+    ///Bracketed lines with every column right-aligned, e.g.
+    ///[  1   -2 ]
+    ///[ 10  3/4 ]
+    pub fn lines(&self) -> Vec<String> {
+        let n = self.dimension();
+        let widths: Vec<usize> = (0..n)
+            .map(|j| {
+                self.rows
+                    .iter()
+                    .map(|r| r[j].as_string().len())
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect();
+
+        self.rows
+            .iter()
+            .map(|r| {
+                let cells: Vec<String> = r
+                    .iter()
+                    .zip(widths.iter())
+                    .map(|(x, width)| format!("{0:>width$}", x.as_string()))
+                    .collect();
+
+                format!("[ {0} ]", cells.join("  "))
+            })
+            .collect()
+    }
+}
+
+///sa_side_by_side() - joins blocks of lines left to right, each block centred vertically against
+///the tallest one.
+pub fn sa_side_by_side(blocks: &[Vec<String>]) -> Vec<String> {
+    let height = blocks.iter().map(|b| b.len()).max().unwrap_or(0);
+    let mut lines = vec![String::new(); height];
+
+    for (index, block) in blocks.iter().enumerate() {
+        let width = block.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let top = (height - block.len()) / 2;
+
+        for (row, line) in lines.iter_mut().enumerate() {
+            let cell = row
+                .checked_sub(top)
+                .and_then(|i| block.get(i))
+                .map(|l| l.as_str())
+                .unwrap_or("");
+
+            if index > 0 {
+                line.push(' ');
+            }
+
+            line.push_str(&format!("{cell:<width$}"));
+        }
+    }
+
+    lines.into_iter().map(|l| l.trim_end().to_owned()).collect()
+}
+
+impl SAMatrixProblem {
+    fn question(&self) -> String {
+        match self.kind {
+            SAMatrixKind::Addition => "Find A + B.".to_owned(),
+            SAMatrixKind::Multiplication => "Find A x B.".to_owned(),
+            SAMatrixKind::Determinant => "Find det(A).".to_owned(),
+            SAMatrixKind::Inverse => format!("Find A{0}.", sa_superscript(-1)),
+        }
+    }
+
+    ///The answer as a block of lines: a bracketed matrix, or a single line for a determinant.
+    fn answer_lines(&self) -> Vec<String> {
+        let answer = match (self.kind, &self.right) {
+            (SAMatrixKind::Addition, Some(right)) => self.left.add(right),
+            (SAMatrixKind::Multiplication, Some(right)) => self.left.multiply(right),
+            (SAMatrixKind::Inverse, _) => self.left.inverse().unwrap_or_else(|| self.left.clone()),
+            _ => return vec![self.left.determinant().as_string()],
+        };
+
+        answer.lines()
+    }
+
+    ///The given matrices labelled 'A =' and 'B =' side by side, plus the answer if asked for.
+    fn labelled_lines(&self, show_answer: bool) -> Vec<String> {
+        let mut blocks = vec![vec!["A =".to_owned()], self.left.lines()];
+
+        if let Some(right) = &self.right {
+            blocks.push(vec!["   B =".to_owned()]);
+            blocks.push(right.lines());
+        }
+
+        if show_answer {
+            blocks.push(vec!["   Answer:".to_owned()]);
+            blocks.push(self.answer_lines());
+        }
+
+        sa_side_by_side(&blocks)
+    }
+
+    fn block_string(&self, show_answer: bool) -> String {
+        let mut output = format!("Problem K{0}: {1}\n", self.number, self.question());
+
+        for line in self.labelled_lines(show_answer) {
+            output.push_str(&format!("    {line}\n"));
+        }
+
+        output
+    }
+}
+
+impl SARender for SAMatrixProblem {
+    fn tag(&self) -> String {
+        "Matrix".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        self.block_string(false)
+    }
+
+    fn instructor_string(&self) -> String {
+        self.block_string(true)
+    }
+
+    ///Written out as an equation, e.g. '[ A ] x [ B ] = [ AB ]', with the answer left blank for
+    ///the student copy.
+    fn vertical_block(&self, show_answer: bool) -> Option<Vec<String>> {
+        let mut blocks = match self.kind {
+            SAMatrixKind::Determinant => vec![vec!["det".to_owned()], self.left.lines()],
+            SAMatrixKind::Inverse => {
+                let mut lines = self.left.lines();
+                lines[0].push_str(&sa_superscript(-1));
+
+                vec![lines]
+            }
+            _ => vec![self.left.lines()],
+        };
+
+        if let Some(right) = &self.right {
+            let symbol = match self.kind {
+                SAMatrixKind::Multiplication => "x",
+                _ => "+",
+            };
+
+            blocks.push(vec![symbol.to_owned()]);
+            blocks.push(right.lines());
+        }
+
+        blocks.push(vec!["=".to_owned()]);
+
+        if show_answer {
+            blocks.push(self.answer_lines());
+        }
+
+        let mut lines = vec![format!("K{0}.", self.number)];
+        lines.extend(sa_side_by_side(&blocks));
+
+        Some(lines)
+    }
+}
+
+///A random n x n matrix. Rational entries are drawn as a numerator over 2, 3 or 4 scaled to the
+///same range as the integers, so they stay between the floor and the ceiling too.
+fn sa_random_matrix(
+    rng: &mut ThreadRng,
+    floor: i64,
+    ceiling: i64,
+    n: usize,
+    rational: bool,
+) -> SAMatrix {
+    let entries = Uniform::from(floor..=ceiling);
+    let denominators = Uniform::from(2..=4i64);
+    let entry = |rng: &mut ThreadRng| {
+        if rational && rng.gen_ratio(1, 3) {
+            let denominator = denominators.sample(rng);
+            let numerators = Uniform::from(floor * denominator..=ceiling * denominator);
+
+            SAFraction::new(numerators.sample(rng), denominator)
+        } else {
+            SAFraction::from(entries.sample(rng))
+        }
+    };
+
+    SAMatrix {
+        rows: (0..n)
+            .map(|_| (0..n).map(|_| entry(rng)).collect())
+            .collect(),
+    }
+}
+
+pub fn sa_generate_matrices(options: &SAMatrixOptions) -> Vec<SAMatrixProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAMatrixProblem> = Vec::new();
+
+    if options.entry_ceiling < options.entry_floor || options.kinds.is_empty() {
+        return generated_package;
+    }
+
+    let (floor, ceiling) = (options.entry_floor as i64, options.entry_ceiling as i64);
+    let n = options.size.dimension();
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let kind = *options.kinds.choose(&mut rng).unwrap();
+        let left = sa_random_matrix(&mut rng, floor, ceiling, n, options.rational_entries);
+        let right = match kind {
+            SAMatrixKind::Addition | SAMatrixKind::Multiplication => Some(sa_random_matrix(
+                &mut rng,
+                floor,
+                ceiling,
+                n,
+                options.rational_entries,
+            )),
+            _ => None,
+        };
+
+        if kind == SAMatrixKind::Inverse {
+            let determinant = left.determinant();
+
+            if determinant.numerator == 0
+                || determinant.numerator.abs() > SA_INVERSE_MAX_DETERMINANT
+                || determinant.denominator > SA_INVERSE_MAX_DETERMINANT
+            {
+                continue;
+            }
+        }
+
+        if generated_package
+            .iter()
+            .any(|p| p.kind == kind && p.left == left && p.right == right)
+        {
+            continue;
+        }
+
+        generated_package.push(SAMatrixProblem {
+            number: generated_package.len() as i32 + 1,
+            kind,
+            left,
+            right,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[i64]]) -> SAMatrix {
+        SAMatrix {
+            rows: rows
+                .iter()
+                .map(|r| r.iter().map(|x| SAFraction::from(*x)).collect())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn inverse_of_a_two_by_two_is_exact() {
+        let inverse = matrix(&[&[4, 7], &[2, 6]]).inverse().unwrap();
+
+        assert_eq!(inverse.lines(), vec!["[  3/5  -7/10 ]", "[ -1/5    2/5 ]"]);
+    }
+
+    #[test]
+    fn inverse_of_a_three_by_three_undoes_it() {
+        let a = matrix(&[&[1, 2, 3], &[0, 1, 4], &[5, 6, 0]]);
+        let inverse = a.inverse().unwrap();
+
+        assert!(inverse == matrix(&[&[-24, 18, 5], &[20, -15, -4], &[-5, 4, 1]]));
+        assert!(a.multiply(&inverse) == matrix(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 1]]));
+    }
+
+    #[test]
+    fn rational_entries_stay_simple_and_in_range() {
+        let options = SAMatrixOptions {
+            problems_num: 20,
+            entry_floor: -3,
+            entry_ceiling: 3,
+            size: SAMatrixSize::ThreeByThree,
+            kinds: vec![SAMatrixKind::Addition],
+            rational_entries: true,
+        };
+        let entries: Vec<SAFraction> = sa_generate_matrices(&options)
+            .iter()
+            .flat_map(|p| [p.left.clone(), p.right.clone().unwrap()])
+            .flat_map(|m| m.rows.into_iter().flatten())
+            .collect();
+
+        assert!(entries.iter().any(|x| !x.is_integer()));
+        assert!(entries.iter().all(|x| x.denominator <= 4
+            && (-3 * x.denominator..=3 * x.denominator).contains(&x.numerator)));
+    }
+
+    #[test]
+    fn singular_matrices_have_no_inverse() {
+        assert!(matrix(&[&[1, 2], &[2, 4]]).inverse().is_none());
+        assert!(matrix(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]])
+            .inverse()
+            .is_none());
+    }
+}
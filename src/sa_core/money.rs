@@ -0,0 +1,297 @@
+use super::{sa_group_digits, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+///How money amounts are written out. Amounts are always kept in integer cents internally, this
+///only decides the symbol, where it goes and which separators are used.
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SACurrencyLocale {
+    ///'$1,234.35'
+    UnitedStates,
+    ///'£1,234.35'
+    UnitedKingdom,
+    ///'1.234,35 €'
+    Euro,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAMoneyKind {
+    ///'Something costs $4.35 and you pay with $10.00. How much change do you get?'
+    MakingChange,
+    ///'$4.35 + $2.10 = '
+    Total,
+    ///'3 * $4.35 = '
+    PriceTimesQuantity,
+}
+
+///Options for money problems. The floor and ceiling are prices in cents.
+pub struct SAMoneyOptions {
+    pub problems_num: i32,
+    pub problems_floor: i32,
+    pub problems_ceiling: i32,
+    pub kind: SAMoneyKind,
+    pub locale: SACurrencyLocale,
+    ///Keeps every price under one dollar/euro/pound, so it can be paid with coins alone.
+    pub coins_only: bool,
+    ///Keeps every price on a multiple of 5 cents, for classes that don't count pennies.
+    pub round_to_five: bool,
+}
+
+///A money problem, all amounts in cents. `second` is the amount paid for MakingChange, the second
+///price for Total and the quantity for PriceTimesQuantity.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAMoneyProblem {
+    pub number: i32,
+    pub kind: SAMoneyKind,
+    pub locale: SACurrencyLocale,
+    pub price: i32,
+    pub second: i32,
+    pub result: i32,
+}
+
+///Input buffer for the money options, see SAProblemInputBuffer.
+pub struct SAMoneyInputBuffer {
+    pub num_of_problems: String,
+    pub floor: String,
+    pub ceiling: String,
+}
+
+impl SACurrencyLocale {
+    pub fn new() -> Self {
+        SACurrencyLocale::UnitedStates
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SACurrencyLocale::UnitedStates => "Dollars ($4.35)".to_owned(),
+            SACurrencyLocale::UnitedKingdom => "Pounds (£4.35)".to_owned(),
+            SACurrencyLocale::Euro => "Euros (4,35 €)".to_owned(),
+        }
+    }
+
+    ///SACurrencyLocale::format() - writes out an amount of cents as currency, e.g. 435 -> '$4.35'.
+    pub fn format(&self, cents: i64) -> String {
+        let whole = cents.unsigned_abs() / 100;
+        let fraction = cents.unsigned_abs() % 100;
+        let sign = if cents < 0 { "-" } else { "" };
+        let (group, decimal) = match self {
+            SACurrencyLocale::Euro => ('.', ','),
+            _ => (',', '.'),
+        };
+        let grouped = sa_group_digits(whole as i64).replace(',', &group.to_string());
+
+        match self {
+            SACurrencyLocale::UnitedStates => format!("{sign}${grouped}{decimal}{fraction:02}"),
+            SACurrencyLocale::UnitedKingdom => format!("{sign}£{grouped}{decimal}{fraction:02}"),
+            SACurrencyLocale::Euro => format!("{sign}{grouped}{decimal}{fraction:02} €"),
+        }
+    }
+
+    ///The bills (or notes) a customer might hand over when making change, in cents.
+    pub fn payments(&self) -> &'static [i32] {
+        match self {
+            SACurrencyLocale::UnitedStates => &[100, 500, 1_000, 2_000, 5_000, 10_000],
+            SACurrencyLocale::UnitedKingdom => &[500, 1_000, 2_000, 5_000],
+            SACurrencyLocale::Euro => &[500, 1_000, 2_000, 5_000, 10_000, 20_000],
+        }
+    }
+}
+
+impl Default for SACurrencyLocale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMoneyKind {
+    pub fn new() -> Self {
+        SAMoneyKind::MakingChange
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAMoneyKind::MakingChange => "Making Change".to_owned(),
+            SAMoneyKind::Total => "Totals".to_owned(),
+            SAMoneyKind::PriceTimesQuantity => "Price * Quantity".to_owned(),
+        }
+    }
+}
+
+impl Default for SAMoneyKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMoneyOptions {
+    pub fn new() -> Self {
+        SAMoneyOptions {
+            problems_num: 0,
+            problems_floor: 0,
+            problems_ceiling: 0,
+            kind: SAMoneyKind::new(),
+            locale: SACurrencyLocale::new(),
+            coins_only: false,
+            round_to_five: false,
+        }
+    }
+}
+
+impl Default for SAMoneyOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAMoneyProblem {
+    fn question(&self) -> String {
+        let money = |cents: i32| self.locale.format(cents as i64);
+
+        match self.kind {
+            SAMoneyKind::MakingChange => format!(
+                "Something costs {0} and you pay with {1}. How much change do you get?",
+                money(self.price),
+                money(self.second)
+            ),
+            SAMoneyKind::Total => format!("{0} + {1} = ", money(self.price), money(self.second)),
+            SAMoneyKind::PriceTimesQuantity => {
+                format!("{0} * {1} = ", self.second, money(self.price))
+            }
+        }
+    }
+}
+
+impl SARender for SAMoneyProblem {
+    fn tag(&self) -> String {
+        "Money".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem ${0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        let answer = self.locale.format(self.result as i64);
+
+        match self.kind {
+            SAMoneyKind::MakingChange => format!(
+                "Problem ${0}: {1} Answer: {2}\n",
+                self.number,
+                self.question(),
+                answer
+            ),
+            _ => format!(
+                "Problem ${0}: {1}{2}\n",
+                self.number,
+                self.question(),
+                answer
+            ),
+        }
+    }
+}
+
+impl SAMoneyInputBuffer {
+    pub fn new() -> Self {
+        SAMoneyInputBuffer {
+            num_of_problems: "".to_owned(),
+            floor: "".to_owned(),
+            ceiling: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAMoneyInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn sa_generate_money(options: &SAMoneyOptions) -> Vec<SAMoneyProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAMoneyProblem> = Vec::new();
+    let floor = options.problems_floor.max(1);
+    let ceiling = if options.coins_only {
+        options.problems_ceiling.min(99)
+    } else {
+        options.problems_ceiling
+    };
+
+    if ceiling < floor {
+        return generated_package;
+    }
+
+    let range = Uniform::from(floor..=ceiling);
+    let quantities = Uniform::from(2..=9);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let mut price = range.sample(&mut rng);
+        let mut other = range.sample(&mut rng);
+
+        if options.round_to_five {
+            price -= price % 5;
+            other -= other % 5;
+
+            if price < floor || other < floor {
+                continue;
+            }
+        }
+
+        let (second, result) = match options.kind {
+            SAMoneyKind::MakingChange => {
+                //Coins-only prices get paid with a single 1.00 coin, anything else with one of
+                //the two smallest bills that covers it.
+                let paid = if options.coins_only {
+                    Some(100)
+                } else {
+                    options
+                        .locale
+                        .payments()
+                        .iter()
+                        .filter(|&&bill| bill > price)
+                        .take(2)
+                        .choose(&mut rng)
+                        .copied()
+                };
+
+                match paid {
+                    Some(paid) => (paid, paid - price),
+                    None => continue,
+                }
+            }
+            //Large ceilings can push a total past i32 cents, so those attempts are skipped.
+            SAMoneyKind::Total => match price.checked_add(other) {
+                Some(total) => (other, total),
+                None => continue,
+            },
+            SAMoneyKind::PriceTimesQuantity => {
+                let quantity = quantities.sample(&mut rng);
+
+                match price.checked_mul(quantity) {
+                    Some(total) => (quantity, total),
+                    None => continue,
+                }
+            }
+        };
+
+        if generated_package
+            .iter()
+            .any(|p| p.price == price && p.second == second)
+        {
+            continue;
+        }
+
+        generated_package.push(SAMoneyProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            locale: options.locale,
+            price,
+            second,
+            result,
+        });
+    }
+
+    generated_package
+}
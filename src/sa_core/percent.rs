@@ -0,0 +1,305 @@
+use super::{sa_group_digits, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAPercentKind {
+    ///'What is 15% of 80?'
+    PercentOf,
+    ///'What percent of 40 is 10?'
+    WhatPercent,
+    ///'3:4 = x:20'
+    Proportion,
+}
+
+///Which answers count as clean. Decimal answers stop at hundredths, so they always terminate.
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAAnswerForm {
+    Integer,
+    TerminatingDecimal,
+}
+
+///Options for percent and proportion problems. The floor and ceiling bound the whole amount (the
+///'80' in '15% of 80', or the known term of the scaled ratio).
+pub struct SAPercentOptions {
+    pub problems_num: i32,
+    pub problems_floor: i32,
+    pub problems_ceiling: i32,
+    pub kind: SAPercentKind,
+    pub form: SAAnswerForm,
+}
+
+///A percent or proportion problem. Answers are kept exactly, in hundredths.
+///
+///For PercentOf, `first` is the percent and `second` the whole; for WhatPercent, `first` is the
+///part and `second` the whole. For Proportion, `first`:`second` is the ratio, `third` is the known
+///term of the scaled ratio and `blank_last` says whether the unknown comes after it or before it.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAPercentProblem {
+    pub number: i32,
+    pub kind: SAPercentKind,
+    pub first: i64,
+    pub second: i64,
+    pub third: i64,
+    pub blank_last: bool,
+    pub answer_hundredths: i64,
+}
+
+///Input buffer for the percent options, see SAProblemInputBuffer.
+pub struct SAPercentInputBuffer {
+    pub num_of_problems: String,
+    pub floor: String,
+    pub ceiling: String,
+}
+
+impl SAPercentKind {
+    pub fn new() -> Self {
+        SAPercentKind::PercentOf
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAPercentKind::PercentOf => "Percent Of".to_owned(),
+            SAPercentKind::WhatPercent => "What Percent".to_owned(),
+            SAPercentKind::Proportion => "Proportion".to_owned(),
+        }
+    }
+}
+
+impl Default for SAPercentKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAAnswerForm {
+    pub fn new() -> Self {
+        SAAnswerForm::Integer
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAAnswerForm::Integer => "Integer".to_owned(),
+            SAAnswerForm::TerminatingDecimal => "Terminating Decimal".to_owned(),
+        }
+    }
+}
+
+impl Default for SAAnswerForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAPercentOptions {
+    pub fn new() -> Self {
+        SAPercentOptions {
+            problems_num: 0,
+            problems_floor: 0,
+            problems_ceiling: 0,
+            kind: SAPercentKind::new(),
+            form: SAAnswerForm::new(),
+        }
+    }
+}
+
+impl Default for SAPercentOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///sa_gcd() - greatest common divisor, always non-negative.
+pub fn sa_gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+///sa_hundredths_string() - writes out an exact number of hundredths as a decimal, dropping
+///trailing zeros, e.g. 1250 -> '12.5' and 1200 -> '12'.
+pub fn sa_hundredths_string(hundredths: i64) -> String {
+    let sign = if hundredths < 0 { "-" } else { "" };
+    let whole = sa_group_digits(hundredths.abs() / 100);
+
+    match hundredths.abs() % 100 {
+        0 => format!("{sign}{whole}"),
+        f if f % 10 == 0 => format!("{sign}{whole}.{0}", f / 10),
+        f => format!("{sign}{whole}.{f:02}"),
+    }
+}
+
+impl SAPercentProblem {
+    fn question(&self) -> String {
+        match self.kind {
+            SAPercentKind::PercentOf => format!(
+                "What is {0}% of {1}?",
+                self.first,
+                sa_group_digits(self.second)
+            ),
+            SAPercentKind::WhatPercent => format!(
+                "What percent of {0} is {1}?",
+                sa_group_digits(self.second),
+                sa_group_digits(self.first)
+            ),
+            SAPercentKind::Proportion if self.blank_last => {
+                format!("{0}:{1} = {2}:x", self.first, self.second, self.third)
+            }
+            SAPercentKind::Proportion => {
+                format!("{0}:{1} = x:{2}", self.first, self.second, self.third)
+            }
+        }
+    }
+}
+
+impl SARender for SAPercentProblem {
+    fn tag(&self) -> String {
+        match self.kind {
+            SAPercentKind::Proportion => "Proportion".to_owned(),
+            _ => "Percent".to_owned(),
+        }
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem %{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        let answer = sa_hundredths_string(self.answer_hundredths);
+        let answer = match self.kind {
+            SAPercentKind::PercentOf => answer,
+            SAPercentKind::WhatPercent => format!("{answer}%"),
+            SAPercentKind::Proportion => format!("x = {answer}"),
+        };
+
+        format!(
+            "Problem %{0}: {1} Answer: {2}\n",
+            self.number,
+            self.question(),
+            answer
+        )
+    }
+}
+
+impl SAPercentInputBuffer {
+    pub fn new() -> Self {
+        SAPercentInputBuffer {
+            num_of_problems: "".to_owned(),
+            floor: "".to_owned(),
+            ceiling: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAPercentInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Exact `numerator / denominator` in hundredths, if it has no more than two decimal places (and
+///none at all for SAAnswerForm::Integer).
+fn sa_clean_hundredths(numerator: i64, denominator: i64, form: SAAnswerForm) -> Option<i64> {
+    let scale = match form {
+        SAAnswerForm::Integer => 1,
+        SAAnswerForm::TerminatingDecimal => 100,
+    };
+
+    if denominator == 0 || (numerator * scale) % denominator != 0 {
+        None
+    } else {
+        Some(numerator * 100 / denominator)
+    }
+}
+
+pub fn sa_generate_percent(options: &SAPercentOptions) -> Vec<SAPercentProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAPercentProblem> = Vec::new();
+    let floor = options.problems_floor.max(1) as i64;
+    let ceiling = options.problems_ceiling as i64;
+
+    if ceiling < floor {
+        return generated_package;
+    }
+
+    let wholes = Uniform::from(floor..=ceiling);
+    let terms = Uniform::from(1..=12i64);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let whole = wholes.sample(&mut rng);
+        let mut problem = SAPercentProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            first: 0,
+            second: whole,
+            third: 0,
+            blank_last: false,
+            answer_hundredths: 0,
+        };
+
+        let answer = match options.kind {
+            SAPercentKind::PercentOf => {
+                //Stick to the percents a student would actually meet.
+                let percent = *[1i64, 5, 10, 15, 20, 25, 30, 40, 50, 60, 75, 80, 90]
+                    .choose(&mut rng)
+                    .unwrap();
+                problem.first = percent;
+
+                sa_clean_hundredths(percent * whole, 100, options.form)
+            }
+            SAPercentKind::WhatPercent => {
+                if whole < 2 {
+                    continue;
+                }
+
+                let part = Uniform::from(1..whole).sample(&mut rng);
+                problem.first = part;
+
+                sa_clean_hundredths(part * 100, whole, options.form)
+            }
+            SAPercentKind::Proportion => {
+                let (a, b) = (terms.sample(&mut rng), terms.sample(&mut rng));
+
+                if a == b || sa_gcd(a, b) != 1 {
+                    continue;
+                }
+
+                problem.first = a;
+                problem.second = b;
+                problem.third = whole;
+                problem.blank_last = rng.gen();
+
+                if problem.blank_last {
+                    sa_clean_hundredths(whole * b, a, options.form)
+                } else {
+                    sa_clean_hundredths(whole * a, b, options.form)
+                }
+            }
+        };
+
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        problem.answer_hundredths = answer;
+
+        if answer == 0
+            || generated_package.iter().any(|p| {
+                p.first == problem.first && p.second == problem.second && p.third == problem.third
+            })
+        {
+            continue;
+        }
+
+        generated_package.push(problem);
+    }
+
+    generated_package
+}
@@ -0,0 +1,205 @@
+use super::{sa_group_digits, sa_place_value_name, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAPlaceValueKind {
+    ///'What digit is in the tens place of 4,582?'
+    DigitInPlace,
+    ///'Write 3,207 in expanded form.'
+    ExpandedForm,
+    ///'Write 3,000 + 200 + 7 in standard form.'
+    StandardForm,
+}
+
+pub struct SAPlaceValueOptions {
+    pub problems_num: i32,
+    pub digits: i32,
+    ///Place value asked about by DigitInPlace problems (1, 10, 100...), or 0 for any place.
+    pub asked_place: i32,
+    pub kind: SAPlaceValueKind,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAPlaceValueProblem {
+    pub number: i32,
+    pub kind: SAPlaceValueKind,
+    pub value: i32,
+    pub place: i32,
+}
+
+///Input buffer for the place value options, see SAProblemInputBuffer.
+pub struct SAPlaceValueInputBuffer {
+    pub num_of_problems: String,
+    pub digits: String,
+}
+
+impl SAPlaceValueKind {
+    pub fn new() -> Self {
+        SAPlaceValueKind::DigitInPlace
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAPlaceValueKind::DigitInPlace => "Digit In Place".to_owned(),
+            SAPlaceValueKind::ExpandedForm => "Expanded Form".to_owned(),
+            SAPlaceValueKind::StandardForm => "Standard Form".to_owned(),
+        }
+    }
+}
+
+impl Default for SAPlaceValueKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAPlaceValueOptions {
+    pub fn new() -> Self {
+        SAPlaceValueOptions {
+            problems_num: 0,
+            digits: 0,
+            asked_place: 0,
+            kind: SAPlaceValueKind::new(),
+        }
+    }
+}
+
+impl Default for SAPlaceValueOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAPlaceValueProblem {
+    pub fn digit(&self) -> i32 {
+        (self.value / self.place.max(1)) % 10
+    }
+
+    ///SAPlaceValueProblem::expanded_form() - e.g. 3207 -> '3,000 + 200 + 7'. Zero digits are
+    ///left out, as is the usual convention.
+    pub fn expanded_form(&self) -> String {
+        let mut parts = Vec::new();
+        let mut place = 1;
+
+        while place <= self.value {
+            let digit = (self.value / place) % 10;
+
+            if digit != 0 {
+                parts.push(sa_group_digits((digit * place) as i64));
+            }
+
+            match place.checked_mul(10) {
+                Some(next) => place = next,
+                None => break,
+            }
+        }
+
+        parts.reverse();
+        parts.join(" + ")
+    }
+
+    fn place_label(&self) -> String {
+        format!("{0}s", sa_place_value_name(self.place))
+    }
+}
+
+impl SARender for SAPlaceValueProblem {
+    fn tag(&self) -> String {
+        "PlaceValue".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        match self.kind {
+            SAPlaceValueKind::DigitInPlace => format!(
+                "Problem P{0}: What digit is in the {1} place of {2}?\n",
+                self.number,
+                self.place_label(),
+                sa_group_digits(self.value as i64)
+            ),
+            SAPlaceValueKind::ExpandedForm => format!(
+                "Problem P{0}: Write {1} in expanded form.\n",
+                self.number,
+                sa_group_digits(self.value as i64)
+            ),
+            SAPlaceValueKind::StandardForm => format!(
+                "Problem P{0}: Write {1} in standard form.\n",
+                self.number,
+                self.expanded_form()
+            ),
+        }
+    }
+
+    fn instructor_string(&self) -> String {
+        match self.kind {
+            SAPlaceValueKind::DigitInPlace => format!(
+                "Problem P{0}: What digit is in the {1} place of {2}? Answer: {3} ({4})\n",
+                self.number,
+                self.place_label(),
+                sa_group_digits(self.value as i64),
+                self.digit(),
+                sa_group_digits((self.digit() * self.place) as i64)
+            ),
+            SAPlaceValueKind::ExpandedForm => format!(
+                "Problem P{0}: Write {1} in expanded form. Answer: {2}\n",
+                self.number,
+                sa_group_digits(self.value as i64),
+                self.expanded_form()
+            ),
+            SAPlaceValueKind::StandardForm => format!(
+                "Problem P{0}: Write {1} in standard form. Answer: {2}\n",
+                self.number,
+                self.expanded_form(),
+                sa_group_digits(self.value as i64)
+            ),
+        }
+    }
+}
+
+impl SAPlaceValueInputBuffer {
+    pub fn new() -> Self {
+        SAPlaceValueInputBuffer {
+            num_of_problems: "".to_owned(),
+            digits: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAPlaceValueInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn sa_generate_place_value(options: &SAPlaceValueOptions) -> Vec<SAPlaceValueProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAPlaceValueProblem> = Vec::new();
+    let digits = options.digits.clamp(1, 9) as u32;
+    let range = Uniform::from(10i32.pow(digits - 1)..10i32.pow(digits));
+    let places: Vec<i32> = (0..digits).map(|p| 10i32.pow(p)).collect();
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let value = range.sample(&mut rng);
+        let place = if places.contains(&options.asked_place) {
+            options.asked_place
+        } else {
+            *places.choose(&mut rng).unwrap()
+        };
+
+        if generated_package.iter().any(|p| p.value == value) {
+            continue;
+        }
+
+        generated_package.push(SAPlaceValueProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            value,
+            place,
+        });
+    }
+
+    generated_package
+}
@@ -0,0 +1,343 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAPolynomialKind {
+    ///'Expand (x + 3)(x - 5).'
+    Expand,
+    ///'Factor x² + 5x + 6.'
+    Factor,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAPolynomialDegree {
+    ///Two linear factors, e.g. (x + 3)(x - 5).
+    Quadratic,
+    ///Three linear factors, e.g. (x - 1)(x + 2)(x + 4).
+    Cubic,
+}
+
+///Options for polynomial problems. Every polynomial is monic with integer roots between the floor
+///and the ceiling, so it always factors into (x - r) terms.
+pub struct SAPolynomialOptions {
+    pub problems_num: i32,
+    pub root_floor: i32,
+    pub root_ceiling: i32,
+    pub degree: SAPolynomialDegree,
+    pub kind: SAPolynomialKind,
+}
+
+///A polynomial in x with integer coefficients, stored lowest power first, so x² - 2x - 15 is
+///[-15, -2, 1].
+#[derive(Clone, PartialEq)]
+pub struct SAPolynomial {
+    pub coefficients: Vec<i64>,
+}
+
+///A polynomial problem, kept as its roots in ascending order; both the factored and the expanded
+///forms are built from them.
+#[derive(Clone, PartialEq)]
+pub struct SAPolynomialProblem {
+    pub number: i32,
+    pub roots: Vec<i64>,
+    pub kind: SAPolynomialKind,
+}
+
+impl SAPolynomialKind {
+    pub fn new() -> Self {
+        SAPolynomialKind::Expand
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAPolynomialKind::Expand => "Expand".to_owned(),
+            SAPolynomialKind::Factor => "Factor".to_owned(),
+        }
+    }
+}
+
+impl Default for SAPolynomialKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAPolynomialDegree {
+    pub fn new() -> Self {
+        SAPolynomialDegree::Quadratic
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAPolynomialDegree::Quadratic => "Quadratic".to_owned(),
+            SAPolynomialDegree::Cubic => "Cubic".to_owned(),
+        }
+    }
+
+    fn factors(&self) -> usize {
+        match self {
+            SAPolynomialDegree::Quadratic => 2,
+            SAPolynomialDegree::Cubic => 3,
+        }
+    }
+}
+
+impl Default for SAPolynomialDegree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAPolynomialOptions {
+    pub fn new() -> Self {
+        SAPolynomialOptions {
+            problems_num: 0,
+            root_floor: 0,
+            root_ceiling: 0,
+            degree: SAPolynomialDegree::new(),
+            kind: SAPolynomialKind::new(),
+        }
+    }
+}
+
+impl Default for SAPolynomialOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///sa_superscript() - writes the number with superscript digits, e.g. 12 -> '¹²', -3 -> '⁻³'.
+pub fn sa_superscript(n: i64) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    n.to_string()
+        .chars()
+        .map(|d| match d.to_digit(10) {
+            Some(digit) => DIGITS[digit as usize],
+            None => '⁻',
+        })
+        .collect()
+}
+
+impl SAPolynomial {
+    ///SAPolynomial::from_roots() - multiplies out (x - r) for each root, e.g. [-3, 5] gives
+    ///x² - 2x - 15.
+    pub fn from_roots(roots: &[i64]) -> Self {
+        let mut coefficients = vec![1];
+
+        for root in roots {
+            let mut next = vec![0; coefficients.len() + 1];
+
+            for (power, c) in coefficients.iter().enumerate() {
+                next[power + 1] += c;
+                next[power] -= root * c;
+            }
+
+            coefficients = next;
+        }
+
+        SAPolynomial { coefficients }
+    }
+
+    ///e.g. 'x³ - 2x² - x + 2'. Zero terms are left out and coefficients of 1 are not written.
+    pub fn as_string(&self) -> String {
+        let mut written = String::new();
+
+        for (power, c) in self.coefficients.iter().enumerate().rev() {
+            if *c == 0 {
+                continue;
+            }
+
+            let sign = match (written.is_empty(), *c < 0) {
+                (true, true) => "-",
+                (true, false) => "",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+            let magnitude = match (c.abs(), power) {
+                (1, 0) => "1".to_owned(),
+                (1, _) => "".to_owned(),
+                (m, _) => m.to_string(),
+            };
+            let variable = match power {
+                0 => "".to_owned(),
+                1 => "x".to_owned(),
+                p => format!("x{0}", sa_superscript(p as i64)),
+            };
+
+            written.push_str(&format!("{sign}{magnitude}{variable}"));
+        }
+
+        if written.is_empty() {
+            "0".to_owned()
+        } else {
+            written
+        }
+    }
+}
+
+impl SAPolynomialProblem {
+    ///The factored form, e.g. '(x + 3)(x - 5)', 'x(x - 4)' or '(x - 2)²(x + 1)'. A root of zero
+    ///is written as a plain x in front, and repeated roots are collected into a power.
+    pub fn factored_string(&self) -> String {
+        let mut written = String::new();
+        let mut roots = self.roots.clone();
+
+        //Roots are kept ascending, so a zero is only ever moved to the front here.
+        roots.sort_by_key(|r| *r != 0);
+
+        let mut i = 0;
+
+        while i < roots.len() {
+            let root = roots[i];
+            let repeats = roots[i..].iter().take_while(|r| **r == root).count();
+            let factor = match root {
+                0 => "x".to_owned(),
+                r if r < 0 => format!("(x + {0})", -r),
+                r => format!("(x - {r})"),
+            };
+
+            written.push_str(&factor);
+
+            if repeats > 1 {
+                written.push_str(&sa_superscript(repeats as i64));
+            }
+
+            i += repeats;
+        }
+
+        written
+    }
+
+    pub fn expanded_string(&self) -> String {
+        SAPolynomial::from_roots(&self.roots).as_string()
+    }
+
+    fn question(&self) -> String {
+        match self.kind {
+            SAPolynomialKind::Expand => format!("Expand {0}.", self.factored_string()),
+            SAPolynomialKind::Factor => format!("Factor {0}.", self.expanded_string()),
+        }
+    }
+}
+
+impl SARender for SAPolynomialProblem {
+    fn tag(&self) -> String {
+        "Polynomial".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem X{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem X{0}: {1} Answer: {2} = {3}\n",
+            self.number,
+            self.question(),
+            self.factored_string(),
+            self.expanded_string()
+        )
+    }
+}
+
+pub fn sa_generate_polynomials(options: &SAPolynomialOptions) -> Vec<SAPolynomialProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAPolynomialProblem> = Vec::new();
+
+    if options.root_ceiling < options.root_floor {
+        return generated_package;
+    }
+
+    let range = Uniform::from(options.root_floor as i64..=options.root_ceiling as i64);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let mut roots: Vec<i64> = (0..options.degree.factors())
+            .map(|_| range.sample(&mut rng))
+            .collect();
+        roots.sort();
+
+        //Two zero roots would make x² a common factor, which is a different skill.
+        if roots.iter().filter(|r| **r == 0).count() > 1 {
+            continue;
+        }
+
+        if generated_package.iter().any(|p| p.roots == roots) {
+            continue;
+        }
+
+        generated_package.push(SAPolynomialProblem {
+            number: generated_package.len() as i32 + 1,
+            roots,
+            kind: options.kind,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem(roots: Vec<i64>, kind: SAPolynomialKind) -> SAPolynomialProblem {
+        SAPolynomialProblem {
+            number: 1,
+            roots,
+            kind,
+        }
+    }
+
+    #[test]
+    fn superscript_writes_digits_and_sign() {
+        assert_eq!(sa_superscript(12), "¹²");
+        assert_eq!(sa_superscript(-3), "⁻³");
+        assert_eq!(sa_superscript(0), "⁰");
+    }
+
+    #[test]
+    fn from_roots_multiplies_out_the_factors() {
+        assert!(SAPolynomial::from_roots(&[-3, 5]).coefficients == vec![-15, -2, 1]);
+        assert_eq!(
+            SAPolynomial::from_roots(&[-2, 1, 1]).as_string(),
+            "x³ - 3x + 2"
+        );
+        assert_eq!(SAPolynomial::from_roots(&[0, 4]).as_string(), "x² - 4x");
+        assert_eq!(SAPolynomial::from_roots(&[-1, 1]).as_string(), "x² - 1");
+    }
+
+    #[test]
+    fn expand_and_factor_are_the_same_polynomial_both_ways() {
+        let expand = problem(vec![-3, 5], SAPolynomialKind::Expand);
+        let factor = problem(vec![-3, 5], SAPolynomialKind::Factor);
+
+        assert_eq!(
+            expand.student_string(),
+            "Problem X1: Expand (x + 3)(x - 5).\n"
+        );
+        assert_eq!(
+            factor.student_string(),
+            "Problem X1: Factor x² - 2x - 15.\n"
+        );
+        assert_eq!(
+            factor.instructor_string(),
+            "Problem X1: Factor x² - 2x - 15. Answer: (x + 3)(x - 5) = x² - 2x - 15\n"
+        );
+    }
+
+    #[test]
+    fn factored_form_collects_zero_and_repeated_roots() {
+        assert_eq!(
+            problem(vec![-1, 0, 4], SAPolynomialKind::Expand).factored_string(),
+            "x(x + 1)(x - 4)"
+        );
+        assert_eq!(
+            problem(vec![-1, 2, 2], SAPolynomialKind::Expand).factored_string(),
+            "(x + 1)(x - 2)²"
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use super::{sa_generate_with_review, SAFigure, SAGeneratorOptions, SAOperation, SAProblem};
+use chrono::{Days, NaiveDate};
+use rand::prelude::*;
+
+///Leitner box review intervals, in days. A correct answer moves a fact up one box, a miss drops
+///it straight back down to the first.
+const SA_LEITNER_INTERVALS: [u64; 5] = [1, 2, 4, 8, 16];
+
+///A single fact (e.g. '7 * 8') a student has seen, along with how they've done on it so far.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAFactRecord {
+    pub constant1: i32,
+    pub constant2: i32,
+    pub operation: SAOperation,
+    pub correct: i32,
+    pub incorrect: i32,
+    pub leitner_box: usize,
+    pub due: NaiveDate,
+}
+
+///Every fact a given student has practiced, persisted locally as a plain text file along with the
+///last worksheet they were handed, which stays pending until its results are recorded.
+pub struct SAStudentRecord {
+    pub name: String,
+    pub facts: Vec<SAFactRecord>,
+    pub pending: Vec<SAProblem>,
+}
+
+impl SAFactRecord {
+    pub fn from(problem: &SAProblem, today: NaiveDate) -> SAFactRecord {
+        SAFactRecord {
+            constant1: problem.constant1,
+            constant2: problem.constant2,
+            operation: problem.operation,
+            correct: 0,
+            incorrect: 0,
+            leitner_box: 0,
+            due: today,
+        }
+    }
+
+    pub fn matches(&self, problem: &SAProblem) -> bool {
+        self.constant1 == problem.constant1
+            && self.constant2 == problem.constant2
+            && self.operation == problem.operation
+    }
+
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due <= today
+    }
+
+    ///A fact is considered weak once it has been missed at least as often as it was answered.
+    pub fn is_weak(&self) -> bool {
+        self.incorrect > 0 && self.incorrect >= self.correct
+    }
+
+    ///Rough priority used for ordering review; higher is more in need of practice.
+    pub fn weakness(&self) -> i32 {
+        self.incorrect * 2 - self.correct
+    }
+
+    pub fn record(&mut self, correct: bool, today: NaiveDate) {
+        if correct {
+            self.correct += 1;
+            self.leitner_box = (self.leitner_box + 1).min(SA_LEITNER_INTERVALS.len() - 1);
+        } else {
+            self.incorrect += 1;
+            self.leitner_box = 0;
+        }
+
+        self.due = today
+            .checked_add_days(Days::new(SA_LEITNER_INTERVALS[self.leitner_box]))
+            .unwrap_or(today);
+    }
+
+    pub fn as_problem(&self, number: i32) -> SAProblem {
+        SAProblem {
+            number,
+            constant1: self.constant1,
+            constant2: self.constant2,
+            operation: self.operation,
+            result: self.operation.calculate(self.constant1, self.constant2),
+            choices: None,
+            figure: SAFigure::None,
+        }
+    }
+
+    fn as_line(&self) -> String {
+        format!(
+            "{0} {1} {2} {3} {4} {5} {6}\n",
+            self.operation.as_string(),
+            self.constant1,
+            self.constant2,
+            self.correct,
+            self.incorrect,
+            self.leitner_box,
+            self.due.format("%Y-%m-%d")
+        )
+    }
+
+    fn from_line(line: &str) -> anyhow::Result<SAFactRecord> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        if fields.len() != 7 {
+            anyhow::bail!(
+                "Expected 7 fields in practice record, found {0}",
+                fields.len()
+            );
+        }
+
+        //SAOperation::from() falls back to addition, so anything that doesn't round-trip is
+        //an operation we never wrote.
+        let operation = SAOperation::from(fields[0].to_owned());
+
+        if operation.as_string() != fields[0] {
+            anyhow::bail!("Unknown operation '{0}' in practice record", fields[0]);
+        }
+
+        Ok(SAFactRecord {
+            operation,
+            constant1: fields[1].parse()?,
+            constant2: fields[2].parse()?,
+            correct: fields[3].parse()?,
+            incorrect: fields[4].parse()?,
+            leitner_box: fields[5]
+                .parse::<usize>()?
+                .min(SA_LEITNER_INTERVALS.len() - 1),
+            due: NaiveDate::parse_from_str(fields[6], "%Y-%m-%d")?,
+        })
+    }
+}
+
+impl SAStudentRecord {
+    pub fn new(name: &str) -> Self {
+        SAStudentRecord {
+            name: name.trim().to_owned(),
+            facts: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    ///Name of the file this student's record lives in. Anything that isn't alphanumeric is
+    ///escaped as its code point in hex between underscores (e.g. 'Ann Lee' -> 'Ann_20_Lee'), so
+    ///the student's name can't wander outside the working directory and no two names share a file.
+    pub fn filename(&self) -> String {
+        let safe_name: String = self
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() {
+                    c.to_string()
+                } else {
+                    format!("_{0:x}_", c as u32)
+                }
+            })
+            .collect();
+
+        format!("SimAri_Practice_{safe_name}.txt")
+    }
+
+    ///Loads the record for the given student, or starts a fresh one if none has been saved yet.
+    pub fn load(name: &str) -> anyhow::Result<SAStudentRecord> {
+        let mut record = SAStudentRecord::new(name);
+
+        if record.name.is_empty() {
+            anyhow::bail!("A student name is required for practice records");
+        }
+
+        let contents = match std::fs::read_to_string(record.filename()) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(record),
+            Err(e) => return Err(e.into()),
+        };
+
+        for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+            match line.strip_prefix("Pending ") {
+                Some(pending) => record.pending.push(sa_pending_from_line(pending)?),
+                None => record.facts.push(SAFactRecord::from_line(line)?),
+            }
+        }
+
+        Ok(record)
+    }
+
+    pub fn save(&self) -> anyhow::Result<String> {
+        use std::{
+            fs::File,
+            io::{prelude::*, LineWriter},
+        };
+
+        let filename = self.filename();
+        let mut file_out = LineWriter::new(File::create(&filename)?);
+
+        for fact in &self.facts {
+            file_out.write_all(fact.as_line().as_bytes())?;
+        }
+
+        for problem in &self.pending {
+            file_out.write_all(sa_pending_as_line(problem).as_bytes())?;
+        }
+
+        Ok(filename)
+    }
+
+    ///Records the pending worksheet, counting every problem not listed in missed as correct, and
+    ///clears it. Returns how many problems were recorded.
+    pub fn record_pending(&mut self, missed: &[i32], today: NaiveDate) -> usize {
+        let pending = std::mem::take(&mut self.pending);
+
+        for problem in &pending {
+            self.record_result(problem, !missed.contains(&problem.number), today);
+        }
+
+        pending.len()
+    }
+
+    pub fn record_result(&mut self, problem: &SAProblem, correct: bool, today: NaiveDate) {
+        match self.facts.iter_mut().find(|f| f.matches(problem)) {
+            Some(fact) => fact.record(correct, today),
+            None => {
+                let mut fact = SAFactRecord::from(problem, today);
+                fact.record(correct, today);
+                self.facts.push(fact);
+            }
+        }
+    }
+
+    ///Facts that fit the given options and are either due for review or weak, most urgent first.
+    pub fn review_facts(
+        &self,
+        options: &SAGeneratorOptions,
+        today: NaiveDate,
+    ) -> Vec<SAFactRecord> {
+        let range = options.problems_floor..=options.problems_ceiling;
+        let mut review: Vec<SAFactRecord> = self
+            .facts
+            .iter()
+            .filter(|f| f.operation == options.operation)
+            .filter(|f| range.contains(&f.constant1) && range.contains(&f.constant2))
+            .filter(|f| f.is_due(today) || f.is_weak())
+            .copied()
+            .collect();
+
+        review.sort_by(|a, b| a.due.cmp(&b.due).then(b.weakness().cmp(&a.weakness())));
+        review
+    }
+}
+
+///sa_generate_for_student() - seeds up to half of the package with facts the student has due or is
+///struggling with, then lets sa_generate_with_review() fill the rest with fresh facts from the
+///options' range. The package comes up short only when the range runs out of facts; callers should
+///compare its length against problems_num.
+pub fn sa_generate_for_student(
+    options: &SAGeneratorOptions,
+    record: &SAStudentRecord,
+    today: NaiveDate,
+) -> Vec<SAProblem> {
+    let review: Vec<SAProblem> = record
+        .review_facts(options, today)
+        .iter()
+        .take(options.problems_num.max(0) as usize / 2)
+        .map(|f| f.as_problem(0))
+        .collect();
+    let mut generated_package = sa_generate_with_review(options, &review);
+
+    generated_package.shuffle(&mut thread_rng());
+
+    for (index, problem) in generated_package.iter_mut().enumerate() {
+        problem.number = index as i32 + 1;
+    }
+
+    generated_package
+}
+
+fn sa_pending_as_line(problem: &SAProblem) -> String {
+    format!(
+        "Pending {0} {1} {2} {3}\n",
+        problem.number,
+        problem.operation.as_string(),
+        problem.constant1,
+        problem.constant2
+    )
+}
+
+fn sa_pending_from_line(line: &str) -> anyhow::Result<SAProblem> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    if fields.len() != 4 {
+        anyhow::bail!(
+            "Expected 4 fields in pending practice problem, found {0}",
+            fields.len()
+        );
+    }
+
+    let operation = SAOperation::from(fields[1].to_owned());
+
+    if operation.as_string() != fields[1] {
+        anyhow::bail!(
+            "Unknown operation '{0}' in pending practice problem",
+            fields[1]
+        );
+    }
+
+    let fact = SAFactRecord {
+        constant1: fields[2].parse()?,
+        constant2: fields[3].parse()?,
+        operation,
+        correct: 0,
+        incorrect: 0,
+        leitner_box: 0,
+        due: NaiveDate::default(),
+    };
+
+    if operation == SAOperation::Division && fact.constant2 == 0 {
+        anyhow::bail!("Pending practice problem divides by zero");
+    }
+
+    Ok(fact.as_problem(fields[0].parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fact_record_survives_a_round_trip_through_its_line() {
+        let fact = SAFactRecord {
+            constant1: 7,
+            constant2: -8,
+            operation: SAOperation::Multiplication,
+            correct: 3,
+            incorrect: 1,
+            leitner_box: 2,
+            due: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        };
+        let line = fact.as_line();
+
+        assert_eq!(line, "Multiplication 7 -8 3 1 2 2024-02-29\n");
+        assert!(SAFactRecord::from_line(&line).unwrap() == fact);
+    }
+
+    #[test]
+    fn fact_record_rejects_an_unknown_operation() {
+        assert!(SAFactRecord::from_line("Exponent 2 3 0 0 0 2024-01-01").is_err());
+    }
+
+    #[test]
+    fn student_package_fills_a_small_range() {
+        let options =
+            SAGeneratorOptions::from(10, 12, 2, false, false, SAOperation::Multiplication);
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let package = sa_generate_for_student(&options, &SAStudentRecord::new("Ann"), today);
+
+        assert_eq!(package.len(), 10);
+    }
+
+    #[test]
+    fn pending_problems_survive_a_round_trip_through_their_lines() {
+        let problem = SAFactRecord {
+            constant1: 9,
+            constant2: 3,
+            operation: SAOperation::Division,
+            correct: 0,
+            incorrect: 0,
+            leitner_box: 0,
+            due: NaiveDate::default(),
+        }
+        .as_problem(4);
+        let line = sa_pending_as_line(&problem);
+
+        assert_eq!(line, "Pending 4 Division 9 3\n");
+        assert!(sa_pending_from_line(line.strip_prefix("Pending ").unwrap()).unwrap() == problem);
+        assert!(sa_pending_from_line("4 Division 9 0").is_err());
+    }
+
+    #[test]
+    fn recording_pending_problems_updates_facts_and_clears_them() {
+        let options = SAGeneratorOptions::from(4, 12, 2, false, false, SAOperation::Multiplication);
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let mut record = SAStudentRecord::new("Ann");
+        record.pending = sa_generate_for_student(&options, &record, today);
+        let missed = record.pending[1];
+
+        assert_eq!(record.record_pending(&[2], today), 4);
+        assert!(record.pending.is_empty());
+        assert_eq!(record.facts.len(), 4);
+        assert_eq!(record.review_facts(&options, today).len(), 1);
+        assert!(record.review_facts(&options, today)[0].matches(&missed));
+    }
+
+    #[test]
+    fn student_package_includes_missed_facts() {
+        let options =
+            SAGeneratorOptions::from(10, 100, 2, false, false, SAOperation::Multiplication);
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let mut record = SAStudentRecord::new("Ann");
+        let missed = SAFactRecord {
+            constant1: 7,
+            constant2: 8,
+            operation: SAOperation::Multiplication,
+            correct: 0,
+            incorrect: 0,
+            leitner_box: 0,
+            due: today,
+        };
+        record.record_result(&missed.as_problem(1), false, today);
+        let package = sa_generate_for_student(&options, &record, today);
+
+        assert_eq!(package.len(), 10);
+        assert!(package.iter().any(|p| missed.matches(p)));
+    }
+
+    #[test]
+    fn filenames_keep_distinct_names_apart() {
+        let spaced = SAStudentRecord::new("Ann Lee").filename();
+        let joined = SAStudentRecord::new("AnnLee").filename();
+
+        assert_eq!(joined, "SimAri_Practice_AnnLee.txt");
+        assert_eq!(spaced, "SimAri_Practice_Ann_20_Lee.txt");
+        assert_eq!(
+            SAStudentRecord::new("../x").filename(),
+            "SimAri_Practice__2e__2e__2f_x.txt"
+        );
+    }
+}
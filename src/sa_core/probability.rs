@@ -0,0 +1,257 @@
+use super::{SAFraction, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAProbabilityExperiment {
+    ///'What is the probability of rolling a sum of 7 with two dice?'
+    DiceSum,
+    ///'How many outfits can be made from 3 shirts and 4 pairs of pants?'
+    Outfits,
+    ///'In how many ways can 3 of 7 runners finish first, second and third?'
+    Permutations,
+    ///'In how many ways can a team of 3 be chosen from 8 students?'
+    Combinations,
+}
+
+///Options for probability and counting problems. `experiments` lists which kinds may be used,
+///and `max_items` caps the group sizes in outfit, permutation and combination problems.
+pub struct SAProbabilityOptions {
+    pub problems_num: i32,
+    pub max_items: i32,
+    pub experiments: Vec<SAProbabilityExperiment>,
+}
+
+///A probability or counting problem. `values` holds the dice sum for DiceSum, up to three
+///category sizes for Outfits (0 when unused), and n then r for Permutations and Combinations.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAProbabilityProblem {
+    pub number: i32,
+    pub experiment: SAProbabilityExperiment,
+    pub values: [i64; 3],
+    pub answer: SAFraction,
+}
+
+const SA_OUTFIT_CATEGORIES: [(&str, &str); 4] = [
+    ("shirt", "shirts"),
+    ("pair of pants", "pairs of pants"),
+    ("pair of shoes", "pairs of shoes"),
+    ("hat", "hats"),
+];
+
+impl SAProbabilityExperiment {
+    pub fn as_string(&self) -> String {
+        match self {
+            SAProbabilityExperiment::DiceSum => "Dice Sums".to_owned(),
+            SAProbabilityExperiment::Outfits => "Outfits".to_owned(),
+            SAProbabilityExperiment::Permutations => "Permutations".to_owned(),
+            SAProbabilityExperiment::Combinations => "Combinations".to_owned(),
+        }
+    }
+}
+
+impl SAProbabilityOptions {
+    pub fn new() -> Self {
+        SAProbabilityOptions {
+            problems_num: 0,
+            max_items: 0,
+            experiments: vec![
+                SAProbabilityExperiment::DiceSum,
+                SAProbabilityExperiment::Outfits,
+                SAProbabilityExperiment::Permutations,
+                SAProbabilityExperiment::Combinations,
+            ],
+        }
+    }
+}
+
+impl Default for SAProbabilityOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Ways two six-sided dice can land on the given sum, out of 36.
+fn sa_dice_ways(sum: i64) -> i64 {
+    (6 - (sum - 7).abs()).max(0)
+}
+
+///n x (n - 1) x ... down r factors, e.g. (7, 3) -> [7, 6, 5].
+fn sa_falling_factors(n: i64, r: i64) -> Vec<i64> {
+    (0..r).map(|i| n - i).collect()
+}
+
+fn sa_product_string(factors: &[i64]) -> String {
+    factors
+        .iter()
+        .map(|f| f.to_string())
+        .collect::<Vec<String>>()
+        .join(" x ")
+}
+
+impl SAProbabilityProblem {
+    fn question(&self) -> String {
+        let [a, b, c] = self.values;
+
+        match self.experiment {
+            SAProbabilityExperiment::DiceSum => {
+                format!("What is the probability of rolling a sum of {a} with two six-sided dice?")
+            }
+            SAProbabilityExperiment::Outfits => {
+                let counts: Vec<String> = [a, b, c]
+                    .iter()
+                    .zip(SA_OUTFIT_CATEGORIES.iter())
+                    .filter(|(count, _)| **count > 0)
+                    .map(|(count, (singular, plural))| match count {
+                        1 => format!("1 {singular}"),
+                        _ => format!("{count} {plural}"),
+                    })
+                    .collect();
+                let listed = match counts.split_last() {
+                    Some((last, rest)) if !rest.is_empty() => {
+                        format!("{0} and {last}", rest.join(", "))
+                    }
+                    _ => counts.join(""),
+                };
+
+                format!("How many different outfits can be made from {listed}?")
+            }
+            SAProbabilityExperiment::Permutations => format!(
+                "In how many different ways can {b} of {a} runners finish in the top {b} places?"
+            ),
+            SAProbabilityExperiment::Combinations => {
+                format!("In how many different ways can a team of {b} be chosen from {a} students?")
+            }
+        }
+    }
+
+    ///How the answer was found, e.g. '6 of 36 outcomes' or '7 x 6 x 5'.
+    fn working(&self) -> String {
+        let [a, b, c] = self.values;
+
+        match self.experiment {
+            SAProbabilityExperiment::DiceSum => format!("{0} of 36 outcomes", sa_dice_ways(a)),
+            SAProbabilityExperiment::Outfits => {
+                let counts: Vec<i64> = [a, b, c].into_iter().filter(|n| *n > 0).collect();
+
+                sa_product_string(&counts)
+            }
+            SAProbabilityExperiment::Permutations => sa_product_string(&sa_falling_factors(a, b)),
+            SAProbabilityExperiment::Combinations => format!(
+                "({0}) / ({1})",
+                sa_product_string(&sa_falling_factors(a, b)),
+                sa_product_string(&sa_falling_factors(b, b))
+            ),
+        }
+    }
+}
+
+impl SARender for SAProbabilityProblem {
+    fn tag(&self) -> String {
+        match self.experiment {
+            SAProbabilityExperiment::DiceSum => "Probability".to_owned(),
+            _ => "Counting".to_owned(),
+        }
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem O{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem O{0}: {1} Answer: {2} ({3})\n",
+            self.number,
+            self.question(),
+            self.answer.as_string(),
+            self.working()
+        )
+    }
+}
+
+pub fn sa_generate_probability(options: &SAProbabilityOptions) -> Vec<SAProbabilityProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAProbabilityProblem> = Vec::new();
+    let max_items = options.max_items.clamp(2, 20) as i64;
+    //Permutations and combinations need n of at least 3 to choose 2 < n, so they're dropped
+    //rather than going over a cap of 2.
+    let experiments: Vec<SAProbabilityExperiment> = options
+        .experiments
+        .iter()
+        .copied()
+        .filter(|experiment| match experiment {
+            SAProbabilityExperiment::Permutations | SAProbabilityExperiment::Combinations => {
+                max_items >= 3
+            }
+            _ => true,
+        })
+        .collect();
+
+    if experiments.is_empty() {
+        return generated_package;
+    }
+
+    let sums = Uniform::from(2..=12i64);
+    let items = Uniform::from(2..=max_items);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let experiment = *experiments.choose(&mut rng).unwrap();
+        let (values, answer) = match experiment {
+            SAProbabilityExperiment::DiceSum => {
+                let sum = sums.sample(&mut rng);
+
+                ([sum, 0, 0], SAFraction::new(sa_dice_ways(sum), 36))
+            }
+            SAProbabilityExperiment::Outfits => {
+                let third = if rng.gen_bool(0.3) {
+                    items.sample(&mut rng)
+                } else {
+                    0
+                };
+                let values = [items.sample(&mut rng), items.sample(&mut rng), third];
+
+                (
+                    values,
+                    SAFraction::from(values.iter().filter(|n| **n > 0).product()),
+                )
+            }
+            SAProbabilityExperiment::Permutations | SAProbabilityExperiment::Combinations => {
+                let n = Uniform::from(3..=max_items).sample(&mut rng);
+                let r = Uniform::from(2..n).sample(&mut rng);
+
+                //Past four factors the arithmetic stops being about counting.
+                if r > 4 {
+                    continue;
+                }
+
+                let arrangements: i64 = sa_falling_factors(n, r).iter().product();
+                let orderings: i64 = sa_falling_factors(r, r).iter().product();
+                let count = match experiment {
+                    SAProbabilityExperiment::Permutations => arrangements,
+                    _ => arrangements / orderings,
+                };
+
+                ([n, r, 0], SAFraction::from(count))
+            }
+        };
+
+        if generated_package
+            .iter()
+            .any(|p| p.experiment == experiment && p.values == values)
+        {
+            continue;
+        }
+
+        generated_package.push(SAProbabilityProblem {
+            number: generated_package.len() as i32 + 1,
+            experiment,
+            values,
+            answer,
+        });
+    }
+
+    generated_package
+}
@@ -0,0 +1,226 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SARomanDirection {
+    ///'Write 1994 in Roman numerals.'
+    ToRoman,
+    ///'Write MCMXCIV as a number.'
+    FromRoman,
+}
+
+///Options for Roman numeral problems. The floor and ceiling are clamped to 1-3999, the range
+///standard Roman numerals can write.
+pub struct SARomanOptions {
+    pub problems_num: i32,
+    pub problems_floor: i32,
+    pub problems_ceiling: i32,
+    pub direction: SARomanDirection,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SARomanProblem {
+    pub number: i32,
+    pub value: i32,
+    pub direction: SARomanDirection,
+}
+
+const SA_ROMAN_SYMBOLS: [(i32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+impl SARomanDirection {
+    pub fn new() -> Self {
+        SARomanDirection::ToRoman
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SARomanDirection::ToRoman => "To Roman".to_owned(),
+            SARomanDirection::FromRoman => "From Roman".to_owned(),
+        }
+    }
+}
+
+impl Default for SARomanDirection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SARomanOptions {
+    pub fn new() -> Self {
+        SARomanOptions {
+            problems_num: 0,
+            problems_floor: 0,
+            problems_ceiling: 0,
+            direction: SARomanDirection::new(),
+        }
+    }
+}
+
+impl Default for SARomanOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///sa_to_roman() - writes the number in Roman numerals, e.g. 1994 -> 'MCMXCIV'. None outside of
+///1-3999.
+pub fn sa_to_roman(n: i32) -> Option<String> {
+    if !(1..=3999).contains(&n) {
+        return None;
+    }
+
+    let mut remaining = n;
+    let mut numeral = String::new();
+
+    for (value, symbol) in SA_ROMAN_SYMBOLS {
+        while remaining >= value {
+            numeral.push_str(symbol);
+            remaining -= value;
+        }
+    }
+
+    Some(numeral)
+}
+
+///sa_from_roman() - reads a Roman numeral, e.g. 'MCMXCIV' -> 1994.
+///
+///Only standard numerals are accepted: subtractive pairs must be one of IV, IX, XL, XC, CD or
+///CM, and no symbol may repeat more than the standard form allows ('IIII', 'IC', 'VX' and 'XM'
+///are all rejected). This falls out of requiring the input to match what sa_to_roman() would
+///write for the same value.
+pub fn sa_from_roman(numeral: &str) -> Option<i32> {
+    let numeral = numeral.trim().to_uppercase();
+    let mut remaining = numeral.as_str();
+    let mut total = 0;
+
+    for (value, symbol) in SA_ROMAN_SYMBOLS {
+        while let Some(rest) = remaining.strip_prefix(symbol) {
+            total += value;
+            remaining = rest;
+        }
+    }
+
+    if !remaining.is_empty() || sa_to_roman(total).as_deref() != Some(numeral.as_str()) {
+        return None;
+    }
+
+    Some(total)
+}
+
+impl SARomanProblem {
+    pub fn numeral(&self) -> String {
+        sa_to_roman(self.value).unwrap_or_default()
+    }
+}
+
+impl SARender for SARomanProblem {
+    fn tag(&self) -> String {
+        "Roman".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        match self.direction {
+            SARomanDirection::ToRoman => format!(
+                "Problem V{0}: Write {1} in Roman numerals.\n",
+                self.number, self.value
+            ),
+            SARomanDirection::FromRoman => format!(
+                "Problem V{0}: Write {1} as a number.\n",
+                self.number,
+                self.numeral()
+            ),
+        }
+    }
+
+    fn instructor_string(&self) -> String {
+        match self.direction {
+            SARomanDirection::ToRoman => format!(
+                "Problem V{0}: Write {1} in Roman numerals. Answer: {2}\n",
+                self.number,
+                self.value,
+                self.numeral()
+            ),
+            SARomanDirection::FromRoman => format!(
+                "Problem V{0}: Write {1} as a number. Answer: {2}\n",
+                self.number,
+                self.numeral(),
+                self.value
+            ),
+        }
+    }
+}
+
+pub fn sa_generate_roman(options: &SARomanOptions) -> Vec<SARomanProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SARomanProblem> = Vec::new();
+    let floor = options.problems_floor.clamp(1, 3999);
+    let ceiling = options.problems_ceiling.clamp(1, 3999);
+
+    if ceiling < floor {
+        return generated_package;
+    }
+
+    let range = Uniform::from(floor..=ceiling);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let value = range.sample(&mut rng);
+
+        if generated_package.iter().any(|p| p.value == value) {
+            continue;
+        }
+
+        generated_package.push(SARomanProblem {
+            number: generated_package.len() as i32 + 1,
+            value,
+            direction: options.direction,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_roman_reads_standard_numerals() {
+        assert_eq!(sa_from_roman("MCMXCIV"), Some(1994));
+        assert_eq!(sa_from_roman("XLII"), Some(42));
+        assert_eq!(sa_from_roman(" mmmcmxcix "), Some(3999));
+        assert_eq!(sa_from_roman("I"), Some(1));
+    }
+
+    #[test]
+    fn from_roman_rejects_non_standard_numerals() {
+        for numeral in ["", "IIII", "IC", "VX", "XM", "VV", "IIX", "MMMM", "ABC"] {
+            assert_eq!(sa_from_roman(numeral), None, "{numeral}");
+        }
+    }
+
+    #[test]
+    fn from_roman_inverts_to_roman() {
+        for n in 1..=3999 {
+            assert_eq!(sa_from_roman(&sa_to_roman(n).unwrap()), Some(n));
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use super::{SAGeneratorOptions, SAOperation, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SARoundingRule {
+    ///Halves round away from zero, the usual classroom rule.
+    HalfUp,
+    ///Halves round to the even neighbor, a.k.a. banker's rounding.
+    HalfEven,
+    Down,
+    Up,
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SARoundingKind {
+    Round,
+    Estimate,
+}
+
+///Options for rounding problems. The operand range, negatives and the operation estimated all
+///come from `base`; `place` is the place value to round to (10, 100, 1000...).
+pub struct SARoundingOptions {
+    pub base: SAGeneratorOptions,
+    pub place: i32,
+    pub rule: SARoundingRule,
+    pub kind: SARoundingKind,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct SARoundingProblem {
+    pub number: i32,
+    pub kind: SARoundingKind,
+    pub constant1: i32,
+    pub constant2: i32,
+    pub operation: SAOperation,
+    pub place: i32,
+    pub rule: SARoundingRule,
+    pub estimate: i64,
+    pub exact: i64,
+}
+
+impl SARoundingRule {
+    pub fn new() -> Self {
+        SARoundingRule::HalfUp
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SARoundingRule::HalfUp => "Half Up".to_owned(),
+            SARoundingRule::HalfEven => "Half Even".to_owned(),
+            SARoundingRule::Down => "Down".to_owned(),
+            SARoundingRule::Up => "Up".to_owned(),
+        }
+    }
+
+    ///SARoundingRule::apply() - rounds the number to the given place value under this rule.
+    ///Works in i64 so rounding up near the top of the i32 range can't overflow.
+    pub fn apply(&self, n: i64, place: i64) -> i64 {
+        let place = place.max(1);
+        let lower = n.div_euclid(place) * place;
+        let upper = lower + place;
+        let offset = n - lower;
+
+        if offset == 0 {
+            return n;
+        }
+
+        match self {
+            SARoundingRule::Down => lower,
+            SARoundingRule::Up => upper,
+            SARoundingRule::HalfUp => match (offset * 2).cmp(&place) {
+                std::cmp::Ordering::Less => lower,
+                std::cmp::Ordering::Greater => upper,
+                std::cmp::Ordering::Equal if n < 0 => lower,
+                std::cmp::Ordering::Equal => upper,
+            },
+            SARoundingRule::HalfEven => match (offset * 2).cmp(&place) {
+                std::cmp::Ordering::Less => lower,
+                std::cmp::Ordering::Greater => upper,
+                std::cmp::Ordering::Equal if (lower / place) % 2 == 0 => lower,
+                std::cmp::Ordering::Equal => upper,
+            },
+        }
+    }
+}
+
+impl Default for SARoundingRule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SARoundingKind {
+    pub fn as_string(&self) -> String {
+        match self {
+            SARoundingKind::Round => "Rounding".to_owned(),
+            SARoundingKind::Estimate => "Estimation".to_owned(),
+        }
+    }
+}
+
+impl SARoundingOptions {
+    pub fn new() -> Self {
+        SARoundingOptions {
+            base: SAGeneratorOptions::new(),
+            place: 10,
+            rule: SARoundingRule::new(),
+            kind: SARoundingKind::Round,
+        }
+    }
+}
+
+impl Default for SARoundingOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///sa_group_digits() - formats a number with comma thousands separators, e.g. '3,476'.
+pub fn sa_group_digits(n: i64) -> String {
+    let digits: Vec<char> = n.unsigned_abs().to_string().chars().collect();
+    let groups: Vec<String> = digits
+        .rchunks(3)
+        .rev()
+        .map(|group| group.iter().collect())
+        .collect();
+    let grouped = groups.join(",");
+
+    if n < 0 {
+        format!("-{grouped}")
+    } else {
+        grouped
+    }
+}
+
+///Same as SAOperation::calculate(), but in i64 so estimates of large numbers can't overflow.
+fn sa_calculate_wide(operation: SAOperation, a: i64, b: i64) -> i64 {
+    match operation {
+        SAOperation::Addition => a + b,
+        SAOperation::Subtraction => a - b,
+        SAOperation::Multiplication => a * b,
+        SAOperation::Division => a / b,
+    }
+}
+
+///sa_place_value_name() - name of a power-of-ten place value, e.g. 100 -> 'hundred'.
+pub fn sa_place_value_name(place: i32) -> String {
+    match place {
+        1 => "one".to_owned(),
+        10 => "ten".to_owned(),
+        100 => "hundred".to_owned(),
+        1_000 => "thousand".to_owned(),
+        10_000 => "ten thousand".to_owned(),
+        100_000 => "hundred thousand".to_owned(),
+        1_000_000 => "million".to_owned(),
+        _ => sa_group_digits(place as i64),
+    }
+}
+
+impl SARoundingProblem {
+    fn rule_note(&self) -> String {
+        match self.rule {
+            SARoundingRule::HalfUp => "".to_owned(),
+            SARoundingRule::HalfEven => " (round halves to even)".to_owned(),
+            SARoundingRule::Down => " (always round down)".to_owned(),
+            SARoundingRule::Up => " (always round up)".to_owned(),
+        }
+    }
+}
+
+impl SARender for SARoundingProblem {
+    fn tag(&self) -> String {
+        self.kind.as_string()
+    }
+
+    fn student_string(&self) -> String {
+        match self.kind {
+            SARoundingKind::Round => format!(
+                "Problem R{0}: Round {1} to the nearest {2}{3}.\n",
+                self.number,
+                sa_group_digits(self.constant1 as i64),
+                sa_place_value_name(self.place),
+                self.rule_note()
+            ),
+            SARoundingKind::Estimate => format!(
+                "Problem E{0}: Estimate {1} {2} {3} by rounding to the nearest {4}{5}.\n",
+                self.number,
+                sa_group_digits(self.constant1 as i64),
+                self.operation.as_symbol(),
+                sa_group_digits(self.constant2 as i64),
+                sa_place_value_name(self.place),
+                self.rule_note()
+            ),
+        }
+    }
+
+    fn instructor_string(&self) -> String {
+        match self.kind {
+            SARoundingKind::Round => format!(
+                "Problem R{0}: Round {1} to the nearest {2}{3}. Answer: {4}\n",
+                self.number,
+                sa_group_digits(self.constant1 as i64),
+                sa_place_value_name(self.place),
+                self.rule_note(),
+                sa_group_digits(self.estimate)
+            ),
+            SARoundingKind::Estimate => format!(
+                "Problem E{0}: Estimate {1} {2} {3} by rounding to the nearest {4}{5}.\n    Estimate: {6} {2} {7} = {8}, Exact: {9}\n",
+                self.number,
+                sa_group_digits(self.constant1 as i64),
+                self.operation.as_symbol(),
+                sa_group_digits(self.constant2 as i64),
+                sa_place_value_name(self.place),
+                self.rule_note(),
+                sa_group_digits(self.rule.apply(self.constant1 as i64, self.place as i64)),
+                sa_group_digits(self.rule.apply(self.constant2 as i64, self.place as i64)),
+                sa_group_digits(self.estimate),
+                sa_group_digits(self.exact)
+            ),
+        }
+    }
+}
+
+pub fn sa_generate_rounding(options: &SARoundingOptions) -> Vec<SARoundingProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SARoundingProblem> = Vec::new();
+    let floor = if options.base.allow_negatives {
+        options.base.problems_floor
+    } else {
+        options.base.problems_floor.max(0)
+    };
+    let range = Uniform::from(floor..=options.base.problems_ceiling.max(floor));
+    //Capped at a million, the largest place with a name, which also keeps the product of two
+    //rounded i32s inside an i64.
+    let place = options.place.clamp(1, 1_000_000);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.base.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let constant1 = range.sample(&mut rng);
+        let constant2 = range.sample(&mut rng);
+
+        //Numbers already on the place value make for a pretty boring problem.
+        if constant1 % place == 0 || generated_package.iter().any(|p| p.constant1 == constant1) {
+            continue;
+        }
+
+        let problem = match options.kind {
+            SARoundingKind::Round => SARoundingProblem {
+                number: generated_package.len() as i32 + 1,
+                kind: options.kind,
+                constant1,
+                constant2: 0,
+                operation: options.base.operation,
+                place,
+                rule: options.rule,
+                estimate: options.rule.apply(constant1 as i64, place as i64),
+                exact: constant1 as i64,
+            },
+            SARoundingKind::Estimate => {
+                let rounded1 = options.rule.apply(constant1 as i64, place as i64);
+                let rounded2 = options.rule.apply(constant2 as i64, place as i64);
+
+                if options.base.operation == SAOperation::Division
+                    && (rounded2 == 0 || constant2 == 0)
+                {
+                    continue;
+                }
+
+                let exact =
+                    sa_calculate_wide(options.base.operation, constant1 as i64, constant2 as i64);
+
+                if exact < 0 && !options.base.allow_negatives {
+                    continue;
+                }
+
+                SARoundingProblem {
+                    number: generated_package.len() as i32 + 1,
+                    kind: options.kind,
+                    constant1,
+                    constant2,
+                    operation: options.base.operation,
+                    place,
+                    rule: options.rule,
+                    estimate: sa_calculate_wide(options.base.operation, rounded1, rounded2),
+                    exact,
+                }
+            }
+        };
+
+        generated_package.push(problem);
+    }
+
+    generated_package
+}
@@ -0,0 +1,403 @@
+use super::{sa_superscript, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAScientificKind {
+    ///'Write 42500 in scientific notation.'
+    ToScientific,
+    ///'Write 4.25 × 10⁴ as a decimal number.'
+    FromScientific,
+    ///'Find (3.2 × 10⁴) × (9 × 10⁻²) in scientific notation.'
+    Multiplication,
+    ///'Find (2.88 × 10³) ÷ (9 × 10⁻²) in scientific notation.'
+    Division,
+    ///'Round 0.0045678 to 2 significant figures.'
+    SignificantFigures,
+}
+
+///Options for scientific notation problems. The exponent floor and ceiling bound the power of ten
+///of every number in scientific notation, questions and answers alike, and are clamped to -20-20.
+///`significant_figures` is how many figures rounding problems round to, clamped to 1-6.
+pub struct SAScientificOptions {
+    pub problems_num: i32,
+    pub exponent_floor: i32,
+    pub exponent_ceiling: i32,
+    pub significant_figures: i32,
+    pub kinds: Vec<SAScientificKind>,
+}
+
+///Input buffer for the scientific notation options, see SAProblemInputBuffer.
+pub struct SAScientificInputBuffer {
+    pub num_of_problems: String,
+    pub exponent_floor: String,
+    pub exponent_ceiling: String,
+    pub significant_figures: String,
+}
+
+///An exact decimal, `coefficient` x 10^`exponent`. The coefficient's digits are exactly the ones
+///written out, so 3.00 (coefficient 300, exponent -2) keeps its trailing zeros.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SADecimal {
+    pub coefficient: i64,
+    pub exponent: i32,
+}
+
+///A scientific notation problem. `second` is only used by Multiplication and Division, and
+///`figures` only by SignificantFigures.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAScientificProblem {
+    pub number: i32,
+    pub kind: SAScientificKind,
+    pub first: SADecimal,
+    pub second: Option<SADecimal>,
+    pub figures: i32,
+    pub answer: SADecimal,
+}
+
+impl SAScientificKind {
+    pub fn new() -> Self {
+        SAScientificKind::ToScientific
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAScientificKind::ToScientific => "To Scientific".to_owned(),
+            SAScientificKind::FromScientific => "From Scientific".to_owned(),
+            SAScientificKind::Multiplication => "Multiplication".to_owned(),
+            SAScientificKind::Division => "Division".to_owned(),
+            SAScientificKind::SignificantFigures => "Significant Figures".to_owned(),
+        }
+    }
+}
+
+impl Default for SAScientificKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAScientificOptions {
+    pub fn new() -> Self {
+        SAScientificOptions {
+            problems_num: 0,
+            exponent_floor: 0,
+            exponent_ceiling: 0,
+            significant_figures: 0,
+            kinds: vec![
+                SAScientificKind::ToScientific,
+                SAScientificKind::FromScientific,
+                SAScientificKind::Multiplication,
+                SAScientificKind::Division,
+                SAScientificKind::SignificantFigures,
+            ],
+        }
+    }
+}
+
+impl Default for SAScientificOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAScientificInputBuffer {
+    pub fn new() -> Self {
+        SAScientificInputBuffer {
+            num_of_problems: "".to_owned(),
+            exponent_floor: "".to_owned(),
+            exponent_ceiling: "".to_owned(),
+            significant_figures: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAScientificInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SADecimal {
+    fn digits(&self) -> usize {
+        self.coefficient.to_string().len()
+    }
+
+    ///The power of ten when written in scientific notation, e.g. 4 for 42500.
+    pub fn power(&self) -> i32 {
+        self.exponent + self.digits() as i32 - 1
+    }
+
+    ///The same value with trailing zeros dropped from the coefficient.
+    pub fn normalized(&self) -> SADecimal {
+        let mut normalized = *self;
+
+        while normalized.coefficient != 0 && normalized.coefficient % 10 == 0 {
+            normalized.coefficient /= 10;
+            normalized.exponent += 1;
+        }
+
+        normalized
+    }
+
+    pub fn multiply(&self, other: &SADecimal) -> SADecimal {
+        SADecimal {
+            coefficient: self.coefficient * other.coefficient,
+            exponent: self.exponent + other.exponent,
+        }
+        .normalized()
+    }
+
+    ///SADecimal::round_to_figures() - rounds half up to the given number of significant figures,
+    ///keeping any zeros that are significant, e.g. 2.996 to 3 figures is 3.00.
+    pub fn round_to_figures(&self, figures: usize) -> SADecimal {
+        let dropped = self.digits().saturating_sub(figures) as u32;
+
+        if dropped == 0 {
+            return *self;
+        }
+
+        let place = 10i64.pow(dropped);
+        let mut rounded = SADecimal {
+            coefficient: self.coefficient / place,
+            exponent: self.exponent + dropped as i32,
+        };
+
+        if (self.coefficient % place) * 2 >= place {
+            rounded.coefficient += 1;
+        }
+
+        //Rounding 9.96 up gives 10.0, one figure too many.
+        if rounded.digits() > figures {
+            rounded.coefficient /= 10;
+            rounded.exponent += 1;
+        }
+
+        rounded
+    }
+
+    ///e.g. '42500', '0.00425' or '3.00'.
+    pub fn decimal_string(&self) -> String {
+        let digits = self.coefficient.to_string();
+
+        if self.exponent >= 0 {
+            return format!("{digits}{0}", "0".repeat(self.exponent as usize));
+        }
+
+        let places = self.exponent.unsigned_abs() as usize;
+
+        if digits.len() > places {
+            let (whole, fraction) = digits.split_at(digits.len() - places);
+
+            format!("{whole}.{fraction}")
+        } else {
+            format!("0.{0}{digits}", "0".repeat(places - digits.len()))
+        }
+    }
+
+    ///e.g. '4.25 × 10⁴' or '3.00 × 10⁰'.
+    pub fn scientific_string(&self) -> String {
+        let digits = self.coefficient.to_string();
+        let (first, rest) = digits.split_at(1);
+        let mantissa = if rest.is_empty() {
+            first.to_owned()
+        } else {
+            format!("{first}.{rest}")
+        };
+
+        format!("{mantissa} × 10{0}", sa_superscript(self.power() as i64))
+    }
+}
+
+impl SAScientificProblem {
+    fn question(&self) -> String {
+        let first = self.first;
+
+        match (self.kind, self.second) {
+            (SAScientificKind::ToScientific, _) => {
+                format!("Write {0} in scientific notation.", first.decimal_string())
+            }
+            (SAScientificKind::FromScientific, _) => {
+                format!("Write {0} as a decimal number.", first.scientific_string())
+            }
+            (SAScientificKind::SignificantFigures, _) => format!(
+                "Round {0} to {1} significant figure{2}.",
+                first.decimal_string(),
+                self.figures,
+                if self.figures == 1 { "" } else { "s" }
+            ),
+            (kind, second) => format!(
+                "Find ({0}) {1} ({2}) in scientific notation.",
+                first.scientific_string(),
+                if kind == SAScientificKind::Division {
+                    "÷"
+                } else {
+                    "×"
+                },
+                second.unwrap_or(first).scientific_string()
+            ),
+        }
+    }
+
+    fn answer_string(&self) -> String {
+        match self.kind {
+            SAScientificKind::FromScientific => self.answer.decimal_string(),
+            SAScientificKind::SignificantFigures => format!(
+                "{0} ({1})",
+                self.answer.decimal_string(),
+                self.answer.scientific_string()
+            ),
+            _ => self.answer.scientific_string(),
+        }
+    }
+}
+
+impl SARender for SAScientificProblem {
+    fn tag(&self) -> String {
+        "Scientific".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem N{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem N{0}: {1} Answer: {2}\n",
+            self.number,
+            self.question(),
+            self.answer_string()
+        )
+    }
+}
+
+///A number with a count of significant figures and a power of ten drawn from the given ranges.
+///The last figure is never zero, so how many figures it has is never in doubt.
+fn sa_random_decimal(
+    rng: &mut ThreadRng,
+    figures: &Uniform<usize>,
+    powers: &Uniform<i32>,
+) -> SADecimal {
+    let figures = figures.sample(rng);
+    let mut coefficient = Uniform::from(1..=9i64).sample(rng);
+
+    for index in 1..figures {
+        let floor = if index == figures - 1 { 1 } else { 0 };
+
+        coefficient = coefficient * 10 + Uniform::from(floor..=9i64).sample(rng);
+    }
+
+    SADecimal {
+        coefficient,
+        exponent: powers.sample(rng) - (figures as i32 - 1),
+    }
+}
+
+pub fn sa_generate_scientific(options: &SAScientificOptions) -> Vec<SAScientificProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAScientificProblem> = Vec::new();
+    let floor = options.exponent_floor.clamp(-20, 20);
+    let ceiling = options.exponent_ceiling.clamp(-20, 20);
+    let figures = options.significant_figures.clamp(1, 6);
+
+    if ceiling < floor || options.kinds.is_empty() {
+        return generated_package;
+    }
+
+    let powers = Uniform::from(floor..=ceiling);
+    let short_figures = Uniform::from(1..=2usize);
+    let long_figures = Uniform::from(2..=4usize);
+    //Numbers to round carry one to three figures more than they're rounded to.
+    let rounded_figures = Uniform::from(figures as usize + 1..=figures as usize + 3);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let kind = *options.kinds.choose(&mut rng).unwrap();
+        let (first, second, answer) = match kind {
+            SAScientificKind::ToScientific | SAScientificKind::FromScientific => {
+                let number = sa_random_decimal(&mut rng, &long_figures, &powers);
+
+                (number, None, number)
+            }
+            SAScientificKind::Multiplication => {
+                let a = sa_random_decimal(&mut rng, &short_figures, &powers);
+                let b = sa_random_decimal(&mut rng, &short_figures, &powers);
+
+                (a, Some(b), a.multiply(&b))
+            }
+            SAScientificKind::Division => {
+                //Built backwards from the quotient, so the division always comes out exact.
+                let quotient = sa_random_decimal(&mut rng, &short_figures, &powers);
+                let divisor = sa_random_decimal(&mut rng, &short_figures, &powers);
+
+                (quotient.multiply(&divisor), Some(divisor), quotient)
+            }
+            SAScientificKind::SignificantFigures => {
+                let number = sa_random_decimal(&mut rng, &rounded_figures, &powers);
+
+                (number, None, number.round_to_figures(figures as usize))
+            }
+        };
+
+        if ![first.power(), answer.power()]
+            .iter()
+            .all(|power| (floor..=ceiling).contains(power))
+        {
+            continue;
+        }
+
+        if generated_package
+            .iter()
+            .any(|p| p.kind == kind && p.first == first && p.second == second)
+        {
+            continue;
+        }
+
+        generated_package.push(SAScientificProblem {
+            number: generated_package.len() as i32 + 1,
+            kind,
+            first,
+            second,
+            figures,
+            answer,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(coefficient: i64, exponent: i32) -> SADecimal {
+        SADecimal {
+            coefficient,
+            exponent,
+        }
+    }
+
+    #[test]
+    fn round_to_figures_rounds_half_up() {
+        assert!(decimal(45678, -7).round_to_figures(2) == decimal(46, -4));
+        assert!(decimal(1234, 0).round_to_figures(2) == decimal(12, 2));
+        assert!(decimal(125, -2).round_to_figures(2) == decimal(13, -1));
+        assert!(decimal(124, -2).round_to_figures(2) == decimal(12, -1));
+    }
+
+    #[test]
+    fn round_to_figures_keeps_significant_zeros() {
+        let rounded = decimal(2996, -3).round_to_figures(3);
+
+        assert!(rounded == decimal(300, -2));
+        assert_eq!(rounded.decimal_string(), "3.00");
+        assert_eq!(decimal(996, 0).round_to_figures(2).decimal_string(), "1000");
+    }
+
+    #[test]
+    fn round_to_figures_leaves_short_numbers_alone() {
+        assert!(decimal(42, -1).round_to_figures(3) == decimal(42, -1));
+    }
+}
@@ -0,0 +1,385 @@
+use super::SARender;
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SASequenceKind {
+    Arithmetic,
+    Geometric,
+    SkipCounting,
+    TwoStep,
+}
+
+///Where the blanks of a sequence problem end up. Blanks never cover the leading terms needed to
+///work out the rule.
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SABlankPosition {
+    End,
+    Random,
+}
+
+pub struct SASequenceOptions {
+    pub problems_num: i32,
+    pub sequence_length: i32,
+    pub blanks: i32,
+    pub blank_position: SABlankPosition,
+    pub start_floor: i32,
+    pub start_ceiling: i32,
+    pub step_floor: i32,
+    pub step_ceiling: i32,
+    pub allow_negatives: bool,
+    pub kind: SASequenceKind,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SASequenceProblem {
+    pub number: i32,
+    pub kind: SASequenceKind,
+    pub terms: Vec<i32>,
+    pub blanks: Vec<usize>,
+    pub steps: (i32, i32),
+}
+
+///Input buffer for the sequence options, see SAProblemInputBuffer.
+pub struct SASequenceInputBuffer {
+    pub num_of_problems: String,
+    pub sequence_length: String,
+    pub blanks: String,
+    pub start_floor: String,
+    pub start_ceiling: String,
+    pub step_floor: String,
+    pub step_ceiling: String,
+}
+
+impl SASequenceKind {
+    pub fn new() -> Self {
+        SASequenceKind::Arithmetic
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SASequenceKind::Arithmetic => "Arithmetic".to_owned(),
+            SASequenceKind::Geometric => "Geometric".to_owned(),
+            SASequenceKind::SkipCounting => "Skip Counting".to_owned(),
+            SASequenceKind::TwoStep => "Two-Step".to_owned(),
+        }
+    }
+
+    ///How many leading terms must stay visible for the rule to be recoverable.
+    fn visible_prefix(&self) -> usize {
+        match self {
+            SASequenceKind::TwoStep => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl Default for SASequenceKind {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SASequenceOptions {
+    pub fn new() -> Self {
+        SASequenceOptions {
+            problems_num: 0,
+            sequence_length: 0,
+            blanks: 0,
+            blank_position: SABlankPosition::End,
+            start_floor: 0,
+            start_ceiling: 0,
+            step_floor: 0,
+            step_ceiling: 0,
+            allow_negatives: false,
+            kind: SASequenceKind::new(),
+        }
+    }
+}
+
+impl Default for SASequenceOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SASequenceProblem {
+    pub fn new() -> Self {
+        SASequenceProblem {
+            number: 0,
+            kind: SASequenceKind::new(),
+            terms: Vec::new(),
+            blanks: Vec::new(),
+            steps: (0, 0),
+        }
+    }
+
+    ///Human-readable description of the rule, e.g. 'add 4' or 'add 2, then add 5'.
+    pub fn rule_string(&self) -> String {
+        let describe = |step: i32| {
+            if step < 0 {
+                format!("subtract {0}", -step)
+            } else {
+                format!("add {step}")
+            }
+        };
+
+        match self.kind {
+            SASequenceKind::Arithmetic | SASequenceKind::SkipCounting => describe(self.steps.0),
+            SASequenceKind::Geometric => format!("multiply by {0}", self.steps.0),
+            SASequenceKind::TwoStep => format!(
+                "{0}, then {1}",
+                describe(self.steps.0),
+                describe(self.steps.1)
+            ),
+        }
+    }
+
+    fn terms_string(&self, show_answers: bool) -> String {
+        self.terms
+            .iter()
+            .enumerate()
+            .map(|(index, term)| {
+                if !self.blanks.contains(&index) {
+                    term.to_string()
+                } else if show_answers {
+                    format!("[{term}]")
+                } else {
+                    "__".to_owned()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+impl Default for SASequenceProblem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SARender for SASequenceProblem {
+    fn tag(&self) -> String {
+        "Sequence".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem Q{0}: {1}\n", self.number, self.terms_string(false))
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem Q{0}: {1} ({2})\n",
+            self.number,
+            self.terms_string(true),
+            self.rule_string()
+        )
+    }
+}
+
+impl SASequenceInputBuffer {
+    pub fn new() -> Self {
+        SASequenceInputBuffer {
+            num_of_problems: "".to_owned(),
+            sequence_length: "".to_owned(),
+            blanks: "".to_owned(),
+            start_floor: "".to_owned(),
+            start_ceiling: "".to_owned(),
+            step_floor: "".to_owned(),
+            step_ceiling: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SASequenceInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Builds the terms for a single sequence, or None if they would overflow or go negative when
+///negatives aren't allowed.
+fn sa_build_terms(
+    kind: SASequenceKind,
+    start: i32,
+    steps: (i32, i32),
+    length: usize,
+    allow_negatives: bool,
+) -> Option<Vec<i32>> {
+    let mut terms = vec![start];
+
+    while terms.len() < length {
+        let last = *terms.last().unwrap();
+        let next = match kind {
+            SASequenceKind::Arithmetic | SASequenceKind::SkipCounting => {
+                last.checked_add(steps.0)?
+            }
+            SASequenceKind::Geometric => last.checked_mul(steps.0)?,
+            SASequenceKind::TwoStep if terms.len() % 2 == 1 => last.checked_add(steps.0)?,
+            SASequenceKind::TwoStep => last.checked_add(steps.1)?,
+        };
+
+        if next < 0 && !allow_negatives {
+            return None;
+        }
+
+        terms.push(next);
+    }
+
+    Some(terms)
+}
+
+pub fn sa_generate_sequences(options: &SASequenceOptions) -> Vec<SASequenceProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SASequenceProblem> = Vec::new();
+    let prefix = options.kind.visible_prefix();
+    let length = (options.sequence_length.max(0) as usize).max(prefix + 1);
+    let blank_count = (options.blanks.max(1) as usize).min(length - prefix);
+    let step_floor = options.step_floor.min(options.step_ceiling);
+    let step_ceiling = options.step_floor.max(options.step_ceiling);
+    let start_range = Uniform::from(
+        options.start_floor.min(options.start_ceiling)
+            ..=options.start_floor.max(options.start_ceiling),
+    );
+    let mut attempts = 0;
+
+    //Geometric sequences with a ratio of 0 or 1 aren't much of a pattern, and a step of 0 isn't
+    //either, so if the ranges only allow for those we simply come back empty-handed.
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let mut pick_step = || {
+            let step = Uniform::from(step_floor..=step_ceiling).sample(&mut rng);
+
+            if options.allow_negatives && options.kind != SASequenceKind::Geometric && rng.gen() {
+                -step
+            } else {
+                step
+            }
+        };
+        let steps = (pick_step(), pick_step());
+
+        if steps.0 == 0
+            || (options.kind == SASequenceKind::Geometric && steps.0.abs() < 2)
+            || (options.kind == SASequenceKind::TwoStep && steps.0 == steps.1)
+        {
+            continue;
+        }
+
+        let start = match options.kind {
+            SASequenceKind::SkipCounting => steps.0 * (start_range.sample(&mut rng) / steps.0),
+            SASequenceKind::Geometric => start_range.sample(&mut rng).max(1),
+            _ => start_range.sample(&mut rng),
+        };
+
+        let terms =
+            match sa_build_terms(options.kind, start, steps, length, options.allow_negatives) {
+                Some(terms) => terms,
+                None => continue,
+            };
+
+        if generated_package.iter().any(|p| p.terms == terms) {
+            continue;
+        }
+
+        let blanks = match options.blank_position {
+            SABlankPosition::End => (length - blank_count..length).collect(),
+            SABlankPosition::Random => {
+                let mut blanks: Vec<usize> =
+                    (prefix..length).choose_multiple(&mut rng, blank_count);
+                blanks.sort();
+                blanks
+            }
+        };
+
+        generated_package.push(SASequenceProblem {
+            number: generated_package.len() as i32 + 1,
+            kind: options.kind,
+            terms,
+            blanks,
+            steps,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_terms_follows_each_rule() {
+        assert_eq!(
+            sa_build_terms(SASequenceKind::Arithmetic, 3, (4, 0), 5, false),
+            Some(vec![3, 7, 11, 15, 19])
+        );
+        assert_eq!(
+            sa_build_terms(SASequenceKind::Geometric, 2, (3, 0), 5, false),
+            Some(vec![2, 6, 18, 54, 162])
+        );
+        assert_eq!(
+            sa_build_terms(SASequenceKind::TwoStep, 1, (2, 5), 6, false),
+            Some(vec![1, 3, 8, 10, 15, 17])
+        );
+    }
+
+    #[test]
+    fn build_terms_rejects_overflow_and_unwanted_negatives() {
+        assert_eq!(
+            sa_build_terms(SASequenceKind::Geometric, i32::MAX / 2, (3, 0), 4, true),
+            None
+        );
+        assert_eq!(
+            sa_build_terms(SASequenceKind::Arithmetic, 5, (-3, 0), 4, false),
+            None
+        );
+        assert_eq!(
+            sa_build_terms(SASequenceKind::Arithmetic, 5, (-3, 0), 4, true),
+            Some(vec![5, 2, -1, -4])
+        );
+    }
+
+    #[test]
+    fn answers_fill_the_blanks_and_name_the_rule() {
+        let problem = SASequenceProblem {
+            number: 1,
+            kind: SASequenceKind::TwoStep,
+            terms: vec![1, 3, 8, 10, 15],
+            blanks: vec![3, 4],
+            steps: (2, 5),
+        };
+
+        assert_eq!(problem.terms_string(false), "1, 3, 8, __, __");
+        assert_eq!(problem.terms_string(true), "1, 3, 8, [10], [15]");
+        assert_eq!(problem.rule_string(), "add 2, then add 5");
+    }
+
+    #[test]
+    fn generated_blanks_leave_the_rule_visible() {
+        let options = SASequenceOptions {
+            problems_num: 20,
+            sequence_length: 6,
+            blanks: 10,
+            blank_position: SABlankPosition::Random,
+            start_floor: 1,
+            start_ceiling: 20,
+            step_floor: 2,
+            step_ceiling: 9,
+            allow_negatives: false,
+            kind: SASequenceKind::TwoStep,
+        };
+        let package = sa_generate_sequences(&options);
+
+        assert!(!package.is_empty());
+
+        for problem in package {
+            assert_eq!(problem.blanks, vec![3, 4, 5]);
+            assert_eq!(
+                sa_build_terms(problem.kind, problem.terms[0], problem.steps, 6, false),
+                Some(problem.terms)
+            );
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use super::{sa_gcd, sa_hundredths_string, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum SAStatisticMeasure {
+    Mean,
+    Median,
+    Mode,
+    Range,
+}
+
+///Options for statistics problems. Every value falls between the floor and ceiling, and
+///`list_size` is how many values go in each data set.
+pub struct SAStatisticsOptions {
+    pub problems_num: i32,
+    pub problems_floor: i32,
+    pub problems_ceiling: i32,
+    pub allow_negatives: bool,
+    pub list_size: i32,
+    pub measure: SAStatisticMeasure,
+    ///Only keep data sets whose mean comes out to a whole number.
+    pub whole_mean: bool,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SAStatisticsProblem {
+    pub number: i32,
+    pub measure: SAStatisticMeasure,
+    pub values: Vec<i32>,
+}
+
+///Input buffer for the statistics options, see SAProblemInputBuffer.
+pub struct SAStatisticsInputBuffer {
+    pub num_of_problems: String,
+    pub floor: String,
+    pub ceiling: String,
+    pub list_size: String,
+}
+
+impl SAStatisticMeasure {
+    pub fn new() -> Self {
+        SAStatisticMeasure::Mean
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            SAStatisticMeasure::Mean => "Mean".to_owned(),
+            SAStatisticMeasure::Median => "Median".to_owned(),
+            SAStatisticMeasure::Mode => "Mode".to_owned(),
+            SAStatisticMeasure::Range => "Range".to_owned(),
+        }
+    }
+}
+
+impl Default for SAStatisticMeasure {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAStatisticsOptions {
+    pub fn new() -> Self {
+        SAStatisticsOptions {
+            problems_num: 0,
+            problems_floor: 0,
+            problems_ceiling: 0,
+            allow_negatives: false,
+            list_size: 5,
+            measure: SAStatisticMeasure::new(),
+            whole_mean: false,
+        }
+    }
+}
+
+impl Default for SAStatisticsOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SAStatisticsProblem {
+    pub fn sum(&self) -> i64 {
+        self.values.iter().map(|&v| v as i64).sum()
+    }
+
+    pub fn sorted(&self) -> Vec<i32> {
+        let mut sorted = self.values.clone();
+        sorted.sort();
+        sorted
+    }
+
+    ///The most common value and how often it appears, if exactly one value is the most common.
+    pub fn mode(&self) -> Option<(i32, usize)> {
+        let sorted = self.sorted();
+        let mut best: Option<(i32, usize)> = None;
+        let mut tied = false;
+
+        let mut runs: Vec<(i32, usize)> = Vec::new();
+
+        for value in sorted {
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+
+        for (value, length) in runs {
+            match best {
+                Some((_, count)) if length == count => tied = true,
+                Some((_, count)) if length < count => {}
+                _ => {
+                    best = Some((value, length));
+                    tied = false;
+                }
+            }
+        }
+
+        match best {
+            Some((_, count)) if tied || count < 2 => None,
+            _ => best,
+        }
+    }
+
+    ///The answer, written exactly. A mean that doesn't come out to hundredths is shown as a
+    ///reduced fraction alongside its rounded decimal.
+    pub fn answer(&self) -> String {
+        let n = self.values.len() as i64;
+        let sorted = self.sorted();
+
+        match self.measure {
+            SAStatisticMeasure::Mean => {
+                if (self.sum() * 100) % n == 0 {
+                    sa_hundredths_string(self.sum() * 100 / n)
+                } else {
+                    let divisor = sa_gcd(self.sum(), n);
+                    let rounded = (self.sum() * 1000 / n + 5 * (self.sum().signum())) / 10;
+
+                    format!(
+                        "{0}/{1}, about {2}",
+                        self.sum() / divisor,
+                        n / divisor,
+                        sa_hundredths_string(rounded)
+                    )
+                }
+            }
+            SAStatisticMeasure::Median => {
+                let middle = sorted.len() / 2;
+
+                if sorted.len() % 2 == 1 {
+                    sorted[middle].to_string()
+                } else {
+                    sa_hundredths_string((sorted[middle - 1] as i64 + sorted[middle] as i64) * 50)
+                }
+            }
+            SAStatisticMeasure::Mode => match self.mode() {
+                Some((mode, _)) => mode.to_string(),
+                None => "no single mode".to_owned(),
+            },
+            SAStatisticMeasure::Range => {
+                (sorted[sorted.len() - 1] as i64 - sorted[0] as i64).to_string()
+            }
+        }
+    }
+
+    ///A short note on how the answer was found, e.g. '(108 / 6)'.
+    fn working(&self) -> String {
+        let sorted = self.sorted();
+        let listed = |values: &[i32]| {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+
+        match self.measure {
+            SAStatisticMeasure::Mean => format!("({0} / {1})", self.sum(), self.values.len()),
+            SAStatisticMeasure::Median => format!("(in order: {0})", listed(&sorted)),
+            SAStatisticMeasure::Mode => match self.mode() {
+                Some((_, count)) => format!("(appears {count} times)"),
+                None => "".to_owned(),
+            },
+            SAStatisticMeasure::Range => {
+                format!("({0} - {1})", sorted[sorted.len() - 1], sorted[0])
+            }
+        }
+    }
+
+    fn question(&self) -> String {
+        let values: Vec<String> = self.values.iter().map(|v| v.to_string()).collect();
+
+        format!(
+            "Find the {0} of {1}.",
+            self.measure.as_string().to_lowercase(),
+            values.join(", ")
+        )
+    }
+}
+
+impl SARender for SAStatisticsProblem {
+    fn tag(&self) -> String {
+        self.measure.as_string()
+    }
+
+    fn student_string(&self) -> String {
+        format!("Problem H{0}: {1}\n", self.number, self.question())
+    }
+
+    fn instructor_string(&self) -> String {
+        let working = self.working();
+        let answer = if working.is_empty() {
+            self.answer()
+        } else {
+            format!("{0} {working}", self.answer())
+        };
+
+        format!(
+            "Problem H{0}: {1} Answer: {2}\n",
+            self.number,
+            self.question(),
+            answer
+        )
+    }
+}
+
+impl SAStatisticsInputBuffer {
+    pub fn new() -> Self {
+        SAStatisticsInputBuffer {
+            num_of_problems: "".to_owned(),
+            floor: "".to_owned(),
+            ceiling: "".to_owned(),
+            list_size: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SAStatisticsInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn sa_generate_statistics(options: &SAStatisticsOptions) -> Vec<SAStatisticsProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SAStatisticsProblem> = Vec::new();
+    let floor = if options.allow_negatives {
+        options.problems_floor
+    } else {
+        options.problems_floor.max(0)
+    };
+    let ceiling = options.problems_ceiling;
+    let list_size = options.list_size.clamp(2, 30) as usize;
+
+    if ceiling < floor {
+        return generated_package;
+    }
+
+    let range = Uniform::from(floor..=ceiling);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let mut problem = SAStatisticsProblem {
+            number: generated_package.len() as i32 + 1,
+            measure: options.measure,
+            values: (0..list_size).map(|_| range.sample(&mut rng)).collect(),
+        };
+
+        //A mode question needs a value that repeats, so plant a copy of one when there isn't one.
+        if options.measure == SAStatisticMeasure::Mode && problem.mode().is_none() {
+            let repeated = *problem.values.choose(&mut rng).unwrap();
+            let replaced = rng.gen_range(0..list_size);
+            problem.values[replaced] = repeated;
+        }
+
+        if (options.whole_mean && problem.sum() % list_size as i64 != 0)
+            || (options.measure == SAStatisticMeasure::Mode && problem.mode().is_none())
+            || generated_package.iter().any(|p| p.values == problem.values)
+        {
+            continue;
+        }
+
+        generated_package.push(problem);
+    }
+
+    generated_package
+}
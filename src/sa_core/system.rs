@@ -0,0 +1,328 @@
+use super::{sa_gcd, SARender};
+use rand::distributions::Uniform;
+use rand::prelude::*;
+
+///Options for systems of two linear equations. The solution floor and ceiling bound both x and
+///y, and the coefficient floor and ceiling bound every x and y coefficient (zero is never used).
+pub struct SASystemOptions {
+    pub problems_num: i32,
+    pub solution_floor: i32,
+    pub solution_ceiling: i32,
+    pub coefficient_floor: i32,
+    pub coefficient_ceiling: i32,
+}
+
+///Input buffer for the system options, see SAProblemInputBuffer.
+pub struct SASystemInputBuffer {
+    pub num_of_problems: String,
+    pub solution_floor: String,
+    pub solution_ceiling: String,
+    pub coefficient_floor: String,
+    pub coefficient_ceiling: String,
+}
+
+///Two equations in x and y, each stored as [a, b, c] for ax + by = c. The determinant of the
+///coefficients is never zero, so the system always has exactly one solution.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SASystemProblem {
+    pub number: i32,
+    pub x: i64,
+    pub y: i64,
+    pub first: [i64; 3],
+    pub second: [i64; 3],
+}
+
+impl SASystemOptions {
+    pub fn new() -> Self {
+        SASystemOptions {
+            problems_num: 0,
+            solution_floor: 0,
+            solution_ceiling: 0,
+            coefficient_floor: 0,
+            coefficient_ceiling: 0,
+        }
+    }
+}
+
+impl Default for SASystemOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SASystemInputBuffer {
+    pub fn new() -> Self {
+        SASystemInputBuffer {
+            num_of_problems: "".to_owned(),
+            solution_floor: "".to_owned(),
+            solution_ceiling: "".to_owned(),
+            coefficient_floor: "".to_owned(),
+            coefficient_ceiling: "".to_owned(),
+        }
+    }
+}
+
+impl Default for SASystemInputBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///A term such as '3x', '-y' or ' - 2y', depending on whether it leads the expression. An empty
+///variable writes a plain constant term.
+fn sa_term(coefficient: i64, variable: &str, leading: bool) -> String {
+    let sign = match (leading, coefficient < 0) {
+        (true, true) => "-",
+        (true, false) => "",
+        (false, true) => " - ",
+        (false, false) => " + ",
+    };
+
+    match coefficient.abs() {
+        0 => "".to_owned(),
+        1 if !variable.is_empty() => format!("{sign}{variable}"),
+        m => format!("{sign}{m}{variable}"),
+    }
+}
+
+///e.g. [2, -3, 5] -> '2x - 3y = 5'. An x coefficient of zero leaves just the y term.
+fn sa_equation_string(equation: [i64; 3]) -> String {
+    let [a, b, c] = equation;
+
+    format!(
+        "{0}{1} = {c}",
+        sa_term(a, "x", true),
+        sa_term(b, "y", a == 0)
+    )
+}
+
+fn sa_scale(equation: [i64; 3], by: i64) -> [i64; 3] {
+    equation.map(|n| n * by)
+}
+
+impl SASystemProblem {
+    fn system_string(&self) -> String {
+        format!(
+            "{0} and {1}",
+            sa_equation_string(self.first),
+            sa_equation_string(self.second)
+        )
+    }
+
+    ///Solving by elimination: scale both equations so the x coefficients match, subtract to
+    ///leave y, then substitute y back into the first equation to find x.
+    pub fn elimination_steps(&self) -> Vec<String> {
+        let mut steps = Vec::new();
+        let [a, b, c] = self.first;
+        let d = self.second[0];
+        //Matching on a multiple with the same sign as a keeps the first multiplier positive.
+        let target = (a * d).abs() / sa_gcd(a, d) * a.signum();
+        let (first_by, second_by) = (target / a, target / d);
+        let first = sa_scale(self.first, first_by);
+        let second = sa_scale(self.second, second_by);
+
+        if first_by != 1 {
+            steps.push(format!(
+                "Multiply the first equation by {first_by}: {0}",
+                sa_equation_string(first)
+            ));
+        }
+
+        if second_by != 1 {
+            steps.push(format!(
+                "Multiply the second equation by {second_by}: {0}",
+                sa_equation_string(second)
+            ));
+        }
+
+        let remaining = [0, first[1] - second[1], first[2] - second[2]];
+
+        steps.push(format!(
+            "Subtract the second equation from the first: {0}",
+            sa_equation_string(remaining)
+        ));
+
+        if remaining[1] != 1 {
+            steps.push(format!(
+                "Divide both sides by {0}: y = {1}",
+                remaining[1], self.y
+            ));
+        }
+
+        let substituted = b * self.y;
+
+        steps.push(format!(
+            "Substitute y = {0} into the first equation: {1}{2} = {c}",
+            self.y,
+            sa_term(a, "x", true),
+            sa_term(substituted, "", false)
+        ));
+
+        if substituted != 0 {
+            steps.push(format!(
+                "{0} both sides: {1} = {2}",
+                if substituted > 0 {
+                    format!("Subtract {substituted} from")
+                } else {
+                    format!("Add {0} to", -substituted)
+                },
+                sa_term(a, "x", true),
+                c - substituted
+            ));
+        }
+
+        if a != 1 {
+            steps.push(format!("Divide both sides by {a}: x = {0}", self.x));
+        }
+
+        steps
+    }
+}
+
+impl SARender for SASystemProblem {
+    fn tag(&self) -> String {
+        "System".to_owned()
+    }
+
+    fn student_string(&self) -> String {
+        format!(
+            "Problem Y{0}: Solve for x and y: {1}\n",
+            self.number,
+            self.system_string()
+        )
+    }
+
+    fn instructor_string(&self) -> String {
+        format!(
+            "Problem Y{0}: Solve for x and y: {1} Answer: x = {2}, y = {3}\n",
+            self.number,
+            self.system_string(),
+            self.x,
+            self.y
+        )
+    }
+
+    fn worked_steps(&self) -> Vec<String> {
+        self.elimination_steps()
+    }
+}
+
+pub fn sa_generate_systems(options: &SASystemOptions) -> Vec<SASystemProblem> {
+    let mut rng = thread_rng();
+    let mut generated_package: Vec<SASystemProblem> = Vec::new();
+
+    if options.solution_ceiling < options.solution_floor
+        || options.coefficient_ceiling < options.coefficient_floor
+        || (options.coefficient_floor == 0 && options.coefficient_ceiling == 0)
+    {
+        return generated_package;
+    }
+
+    let solutions = Uniform::from(options.solution_floor as i64..=options.solution_ceiling as i64);
+    let coefficients =
+        Uniform::from(options.coefficient_floor as i64..=options.coefficient_ceiling as i64);
+    let mut attempts = 0;
+
+    while generated_package.len() < options.problems_num as usize && attempts < 10_000 {
+        attempts += 1;
+
+        let (x, y) = (solutions.sample(&mut rng), solutions.sample(&mut rng));
+        let [a, b, d, e] = [(); 4].map(|_| coefficients.sample(&mut rng));
+
+        //A zero determinant means the equations are either the same line or parallel ones.
+        if [a, b, d, e].contains(&0) || a * e - b * d == 0 {
+            continue;
+        }
+
+        let first = [a, b, a * x + b * y];
+        let second = [d, e, d * x + e * y];
+
+        if generated_package
+            .iter()
+            .any(|p| p.first == first && p.second == second)
+        {
+            continue;
+        }
+
+        generated_package.push(SASystemProblem {
+            number: generated_package.len() as i32 + 1,
+            x,
+            y,
+            first,
+            second,
+        });
+    }
+
+    generated_package
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elimination_steps_scale_subtract_and_substitute() {
+        let problem = SASystemProblem {
+            number: 1,
+            x: 1,
+            y: 2,
+            first: [2, 3, 8],
+            second: [3, -1, 1],
+        };
+
+        assert_eq!(
+            problem.elimination_steps(),
+            vec![
+                "Multiply the first equation by 3: 6x + 9y = 24",
+                "Multiply the second equation by 2: 6x - 2y = 2",
+                "Subtract the second equation from the first: 11y = 22",
+                "Divide both sides by 11: y = 2",
+                "Substitute y = 2 into the first equation: 2x + 6 = 8",
+                "Subtract 6 from both sides: 2x = 2",
+                "Divide both sides by 2: x = 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn elimination_steps_keep_the_first_multiplier_positive() {
+        let problem = SASystemProblem {
+            number: 1,
+            x: 1,
+            y: 2,
+            first: [-1, 1, 1],
+            second: [2, 1, 4],
+        };
+        let steps = problem.elimination_steps();
+
+        assert_eq!(steps[0], "Multiply the first equation by 2: -2x + 2y = 2");
+        assert_eq!(
+            steps[2],
+            "Subtract the second equation from the first: 3y = 6"
+        );
+        assert_eq!(steps.last().unwrap(), "Divide both sides by -1: x = 1");
+    }
+
+    #[test]
+    fn elimination_steps_end_on_the_solution() {
+        let options = SASystemOptions {
+            problems_num: 50,
+            solution_floor: -9,
+            solution_ceiling: 9,
+            coefficient_floor: -6,
+            coefficient_ceiling: 6,
+        };
+
+        for problem in sa_generate_systems(&options) {
+            let steps = problem.elimination_steps();
+
+            assert!(steps
+                .iter()
+                .any(|s| s.ends_with(&format!("y = {0}", problem.y))));
+            assert!(steps
+                .last()
+                .unwrap()
+                .ends_with(&format!("x = {0}", problem.x)));
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use super::{sa_generate, SAGeneratorOptions, SAOperation, SAProblem, SARender};
+use rand::prelude::*;
+
+///A countable noun along with its plural, which is spelled out rather than derived so irregular
+///plurals (e.g. 'cherry'/'cherries', 'mouse'/'mice') come out right.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAWordNoun {
+    pub singular: &'static str,
+    pub plural: &'static str,
+}
+
+///A word problem template, tagged with the operation it describes.
+///
+///Supported placeholders are '{name}' and '{other}' (two different names), '{a}' and '{b}' (the
+///constants), '{a_items}' and '{b_items}' (the constants with the item, pluralized to match),
+///'{a_containers}', '{a_friends}' and '{b_friends}' (likewise with a container or 'friend'),
+///'{item}'/'{items}' and '{container}'/'{containers}'.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SAWordTemplate {
+    pub operation: SAOperation,
+    pub text: &'static str,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SAWordProblem {
+    pub problem: SAProblem,
+    pub text: String,
+    pub answer: String,
+}
+
+pub const SA_WORD_NAMES: [&str; 16] = [
+    "Ava", "Mateo", "Priya", "Kenji", "Zoe", "Omar", "Lucia", "Noah", "Amara", "Liam", "Mei",
+    "Diego", "Hannah", "Kwame", "Sofia", "Ethan",
+];
+
+pub const SA_WORD_ITEMS: [SAWordNoun; 10] = [
+    SAWordNoun {
+        singular: "apple",
+        plural: "apples",
+    },
+    SAWordNoun {
+        singular: "sticker",
+        plural: "stickers",
+    },
+    SAWordNoun {
+        singular: "marble",
+        plural: "marbles",
+    },
+    SAWordNoun {
+        singular: "cherry",
+        plural: "cherries",
+    },
+    SAWordNoun {
+        singular: "peach",
+        plural: "peaches",
+    },
+    SAWordNoun {
+        singular: "book",
+        plural: "books",
+    },
+    SAWordNoun {
+        singular: "toy mouse",
+        plural: "toy mice",
+    },
+    SAWordNoun {
+        singular: "pencil",
+        plural: "pencils",
+    },
+    SAWordNoun {
+        singular: "seashell",
+        plural: "seashells",
+    },
+    SAWordNoun {
+        singular: "loaf of bread",
+        plural: "loaves of bread",
+    },
+];
+
+pub const SA_WORD_FRIEND: SAWordNoun = SAWordNoun {
+    singular: "friend",
+    plural: "friends",
+};
+
+pub const SA_WORD_CONTAINERS: [SAWordNoun; 5] = [
+    SAWordNoun {
+        singular: "bag",
+        plural: "bags",
+    },
+    SAWordNoun {
+        singular: "box",
+        plural: "boxes",
+    },
+    SAWordNoun {
+        singular: "basket",
+        plural: "baskets",
+    },
+    SAWordNoun {
+        singular: "shelf",
+        plural: "shelves",
+    },
+    SAWordNoun {
+        singular: "crate",
+        plural: "crates",
+    },
+];
+
+pub const SA_WORD_TEMPLATES: [SAWordTemplate; 10] = [
+    SAWordTemplate {
+        operation: SAOperation::Addition,
+        text: "{name} has {a_items} and buys {b} more. How many {items} does {name} have now?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Addition,
+        text: "{name} collected {a_items} and {other} collected {b_items}. How many {items} did they collect altogether?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Addition,
+        text: "There are {a_items} in a {container}. {name} puts in {b} more. How many {items} are in the {container} now?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Subtraction,
+        text: "{name} had {a_items} and gave {b} to {other}. How many {items} does {name} have left?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Subtraction,
+        text: "A {container} holds {a_items}. {name} takes out {b}. How many {items} are still in the {container}?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Multiplication,
+        text: "{name} has {a_containers} with {b_items} in each. How many {items} does {name} have in all?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Multiplication,
+        text: "{name} invites {a_friends}, and each one brings {b_items}. How many {items} do they bring in total?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Division,
+        text: "{name} shares {a_items} equally among {b_friends}. How many {items} does each friend get?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Division,
+        text: "{name} packs {a_items} into {containers}, {b} to a {container}. How many full {containers} does {name} pack?",
+    },
+    SAWordTemplate {
+        operation: SAOperation::Subtraction,
+        text: "{name} needs {a_items} for a project and already has {b}. How many more {items} does {name} need?",
+    },
+];
+
+impl SAWordNoun {
+    ///SAWordNoun::count() - the number followed by the noun, pluralized to match (e.g. '1 apple',
+    ///'0 apples', '12 apples').
+    pub fn count(&self, n: i32) -> String {
+        if n.abs() == 1 {
+            format!("{n} {0}", self.singular)
+        } else {
+            format!("{n} {0}", self.plural)
+        }
+    }
+}
+
+impl SAWordTemplate {
+    pub fn fill(
+        &self,
+        problem: &SAProblem,
+        names: (&str, &str),
+        item: &SAWordNoun,
+        container: &SAWordNoun,
+    ) -> String {
+        self.text
+            .replace("{name}", names.0)
+            .replace("{other}", names.1)
+            .replace("{a_items}", &item.count(problem.constant1))
+            .replace("{b_items}", &item.count(problem.constant2))
+            .replace("{a_containers}", &container.count(problem.constant1))
+            .replace("{a_friends}", &SA_WORD_FRIEND.count(problem.constant1))
+            .replace("{b_friends}", &SA_WORD_FRIEND.count(problem.constant2))
+            .replace("{a}", &problem.constant1.to_string())
+            .replace("{b}", &problem.constant2.to_string())
+            .replace("{items}", item.plural)
+            .replace("{item}", item.singular)
+            .replace("{containers}", container.plural)
+            .replace("{container}", container.singular)
+    }
+
+    ///The answer with its units, e.g. '168 apples', or '4 apples, with 2 left over' for division.
+    pub fn answer(&self, problem: &SAProblem, item: &SAWordNoun, container: &SAWordNoun) -> String {
+        let noun = if self.text.contains("full {containers}") {
+            container
+        } else {
+            item
+        };
+        let mut answer = noun.count(problem.result);
+
+        if problem.operation == SAOperation::Division && problem.constant2 != 0 {
+            let remainder = problem.constant1 % problem.constant2;
+
+            if remainder != 0 {
+                answer.push_str(&format!(", with {0} left over", item.count(remainder)));
+            }
+        }
+
+        answer
+    }
+}
+
+impl SAWordProblem {
+    ///SAWordProblem::from() - dresses the problem up with a random template for its operation,
+    ///along with random names, item and container.
+    pub fn from(problem: SAProblem) -> SAWordProblem {
+        let mut rng = thread_rng();
+        let template = SA_WORD_TEMPLATES
+            .iter()
+            .filter(|t| t.operation == problem.operation)
+            .choose(&mut rng)
+            .unwrap();
+        let names: Vec<&&str> = SA_WORD_NAMES.choose_multiple(&mut rng, 2).collect();
+        let item = SA_WORD_ITEMS.choose(&mut rng).unwrap();
+        let container = SA_WORD_CONTAINERS.choose(&mut rng).unwrap();
+
+        SAWordProblem {
+            problem,
+            text: template.fill(&problem, (names[0], names[1]), item, container),
+            answer: template.answer(&problem, item, container),
+        }
+    }
+}
+
+impl SARender for SAWordProblem {
+    fn tag(&self) -> String {
+        format!("Word{0}", self.problem.tag())
+    }
+
+    fn student_string(&self) -> String {
+        let mut output = format!("Problem W{0}: {1}\n", self.problem.number, self.text);
+
+        if let Some(choices) = self.problem.choices {
+            output.push_str(&choices.as_string());
+        }
+
+        output
+    }
+
+    fn instructor_string(&self) -> String {
+        let choice_marker = match self.problem.choices {
+            Some(choices) => format!(" ({0})", choices.correct_letter()),
+            None => "".to_owned(),
+        };
+
+        let mut output = format!(
+            "Problem W{0}: {1}\n    Answer: {2}{3} ({4} {5} {6} = {7})\n",
+            self.problem.number,
+            self.text,
+            self.answer,
+            choice_marker,
+            self.problem.constant1,
+            self.problem.operation.as_symbol(),
+            self.problem.constant2,
+            self.problem.result
+        );
+
+        if let Some(choices) = self.problem.choices {
+            output.push_str(&choices.as_string());
+        }
+
+        output
+    }
+
+    fn worked_steps(&self) -> Vec<String> {
+        self.problem.worked_solution()
+    }
+}
+
+///sa_generate_word_problems() - generates problems with sa_generate() and turns each into a word
+///problem. Negatives never make sense for counting things, so they're always disallowed here.
+pub fn sa_generate_word_problems(options: &SAGeneratorOptions) -> Vec<SAWordProblem> {
+    let counting_options = SAGeneratorOptions::from(
+        options.problems_num,
+        options.problems_ceiling,
+        options.problems_floor.max(1),
+        false,
+        options.multiple_choice,
+        options.operation,
+    );
+
+    sa_generate(&counting_options)
+        .into_iter()
+        .map(SAWordProblem::from)
+        .collect()
+}
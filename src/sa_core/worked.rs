@@ -0,0 +1,395 @@
+use super::{SAOperation, SAProblem, SARender};
+
+///A single bring-down/divide/multiply/subtract round of long division.
+#[derive(Copy, Clone, PartialEq)]
+pub struct SADivisionStep {
+    ///Index of the dividend digit that was just brought down, counted from the left.
+    pub position: usize,
+    pub partial: i64,
+    pub quotient_digit: i64,
+    pub product: i64,
+    pub difference: i64,
+}
+
+///Digits of a non-negative number, ones place first.
+fn sa_digits(n: i64) -> Vec<i64> {
+    let mut digits = Vec::new();
+    let mut remaining = n;
+
+    loop {
+        digits.push(remaining % 10);
+        remaining /= 10;
+
+        if remaining == 0 {
+            break digits;
+        }
+    }
+}
+
+fn sa_place_name(index: usize) -> String {
+    const PLACES: [&str; 10] = [
+        "Ones",
+        "Tens",
+        "Hundreds",
+        "Thousands",
+        "Ten thousands",
+        "Hundred thousands",
+        "Millions",
+        "Ten millions",
+        "Hundred millions",
+        "Billions",
+    ];
+
+    match PLACES.get(index) {
+        Some(place) => place.to_string(),
+        None => format!("10^{index}"),
+    }
+}
+
+///sa_long_division_steps() - every round of long division of a non-negative dividend by a
+///positive divisor, skipping the leading digits that are too small to divide into.
+pub fn sa_long_division_steps(dividend: i64, divisor: i64) -> Vec<SADivisionStep> {
+    let digits: Vec<i64> = sa_digits(dividend).into_iter().rev().collect();
+    let mut steps = Vec::new();
+    let mut remainder = 0i64;
+
+    for (position, digit) in digits.iter().enumerate() {
+        let partial = remainder * 10 + digit;
+        let quotient_digit = partial / divisor;
+
+        //Nothing to write yet while the partial dividend is still smaller than the divisor,
+        //unless this is the final digit and we still need a step to show the remainder.
+        if steps.is_empty() && quotient_digit == 0 && position + 1 < digits.len() {
+            remainder = partial;
+            continue;
+        }
+
+        let product = quotient_digit * divisor;
+        remainder = partial - product;
+
+        steps.push(SADivisionStep {
+            position,
+            partial,
+            quotient_digit,
+            product,
+            difference: remainder,
+        });
+    }
+
+    steps
+}
+
+fn sa_worked_addition(x: i64, y: i64) -> Vec<String> {
+    let (xd, yd) = (sa_digits(x), sa_digits(y));
+    let columns = xd.len().max(yd.len());
+    let sum = x + y;
+    let width = sum.to_string().len().max(columns);
+    let mut carry_row = vec![' '; width];
+    let mut steps = Vec::new();
+    let mut carry = 0;
+
+    for i in 0..columns {
+        let (a, b) = (*xd.get(i).unwrap_or(&0), *yd.get(i).unwrap_or(&0));
+        let column_sum = a + b + carry;
+        let mut step = if carry > 0 {
+            format!(
+                "{0}: {a} + {b} + {carry} (carried) = {column_sum}, write {1}",
+                sa_place_name(i),
+                column_sum % 10
+            )
+        } else {
+            format!(
+                "{0}: {a} + {b} = {column_sum}, write {1}",
+                sa_place_name(i),
+                column_sum % 10
+            )
+        };
+
+        carry = column_sum / 10;
+
+        if carry > 0 {
+            step.push_str(&format!(", carry {carry}"));
+            carry_row[width - i - 2] = char::from_digit(carry as u32, 10).unwrap();
+        }
+
+        steps.push(step);
+    }
+
+    if carry > 0 {
+        steps.push(format!(
+            "{0}: bring down the carried {carry}",
+            sa_place_name(columns)
+        ));
+    }
+
+    let carries: String = carry_row.into_iter().collect();
+    let mut lines = Vec::new();
+
+    if !carries.trim().is_empty() {
+        lines.push(format!("  {carries}"));
+    }
+
+    lines.push(format!("  {x:>width$}"));
+    lines.push(format!("+ {y:>width$}"));
+    lines.push("-".repeat(width + 2));
+    lines.push(format!("  {sum:>width$}"));
+    lines.extend(steps);
+    lines
+}
+
+fn sa_worked_subtraction(x: i64, y: i64) -> Vec<String> {
+    let original = sa_digits(x);
+    let mut xd = original.clone();
+    let yd = sa_digits(y);
+    let mut steps = Vec::new();
+
+    for i in 0..xd.len() {
+        let b = *yd.get(i).unwrap_or(&0);
+        let lent = xd[i] != original[i];
+
+        if xd[i] < 0 {
+            //Only happens when a 0 had to lend, so it borrows in turn and is left with 9.
+            xd[i] += 10;
+            xd[i + 1] -= 1;
+            steps.push(format!(
+                "{0}: the 0 lent 1, so borrow 1 from the {1} to make 9; 9 - {b} = {2}",
+                sa_place_name(i),
+                sa_place_name(i + 1).to_lowercase(),
+                xd[i] - b
+            ));
+        } else if xd[i] < b {
+            xd[i] += 10;
+            xd[i + 1] -= 1;
+            steps.push(format!(
+                "{0}: {1} is less than {b}, borrow 1 from the {2} to make {3}; {3} - {b} = {4}",
+                sa_place_name(i),
+                xd[i] - 10,
+                sa_place_name(i + 1).to_lowercase(),
+                xd[i],
+                xd[i] - b
+            ));
+        } else if i >= yd.len() && !lent {
+            steps.push(format!("{0}: bring down {1}", sa_place_name(i), xd[i]));
+        } else {
+            steps.push(format!(
+                "{0}: {1}{2} - {b} = {3}",
+                sa_place_name(i),
+                xd[i],
+                if lent { " (after lending 1)" } else { "" },
+                xd[i] - b
+            ));
+        }
+    }
+
+    steps
+}
+
+fn sa_worked_multiplication(x: i64, y: i64) -> Vec<String> {
+    let mut steps = Vec::new();
+
+    if y < 10 {
+        let mut carry = 0;
+
+        for (i, digit) in sa_digits(x).iter().enumerate() {
+            let product = digit * y + carry;
+            let mut step = if carry > 0 {
+                format!(
+                    "{0}: {digit} x {y} + {carry} (carried) = {product}, write {1}",
+                    sa_place_name(i),
+                    product % 10
+                )
+            } else {
+                format!(
+                    "{0}: {digit} x {y} = {product}, write {1}",
+                    sa_place_name(i),
+                    product % 10
+                )
+            };
+
+            carry = product / 10;
+
+            if carry > 0 {
+                step.push_str(&format!(", carry {carry}"));
+            }
+
+            steps.push(step);
+        }
+
+        if carry > 0 {
+            steps.push(format!("Write the final carried {carry}"));
+        }
+
+        return steps;
+    }
+
+    let mut partials = Vec::new();
+
+    for (i, digit) in sa_digits(y).iter().enumerate() {
+        let place_value = digit * 10i64.pow(i as u32);
+        let partial = x * place_value;
+
+        steps.push(format!(
+            "{0}: {x} x {place_value} = {partial}",
+            sa_place_name(i)
+        ));
+        partials.push(partial.to_string());
+    }
+
+    steps.push(format!(
+        "Add the partial products: {0} = {1}",
+        partials.join(" + "),
+        x * y
+    ));
+    steps
+}
+
+fn sa_worked_division(x: i64, y: i64) -> Vec<String> {
+    let digits: Vec<i64> = sa_digits(x).into_iter().rev().collect();
+    let mut steps = Vec::new();
+
+    for (index, step) in sa_long_division_steps(x, y).iter().enumerate() {
+        let lead = if index == 0 {
+            format!("Divide {0} by {y}", step.partial)
+        } else {
+            format!(
+                "Bring down {0} to make {1}",
+                digits[step.position], step.partial
+            )
+        };
+
+        steps.push(format!(
+            "{lead}: {0} x {y} = {1}, {2} - {1} = {3}",
+            step.quotient_digit, step.product, step.partial, step.difference
+        ));
+    }
+
+    let remainder = x % y;
+
+    if remainder > 0 {
+        steps.push(format!(
+            "Quotient is {0} with a remainder of {remainder}",
+            x / y
+        ));
+    } else {
+        steps.push(format!("Quotient is {0} with no remainder", x / y));
+    }
+
+    steps
+}
+
+impl SAProblem {
+    ///SAProblem::worked_solution() - step-by-step working for the problem.
+    ///
+    ///Negative constants are first reduced to the equivalent problem on magnitudes, with a line
+    ///explaining the sign, and the column method is then worked on that.
+    pub fn worked_solution(&self) -> Vec<String> {
+        let (a, b) = (self.constant1 as i64, self.constant2 as i64);
+
+        match self.operation {
+            SAOperation::Addition | SAOperation::Subtraction => {
+                let subtracting = self.operation == SAOperation::Subtraction;
+
+                if subtracting && a >= b && b >= 0 {
+                    return sa_worked_subtraction(a, b);
+                }
+
+                //Anything else is reduced to adding the opposite, which lets both operations
+                //share the sign logic below.
+                let b = if subtracting { -b } else { b };
+
+                if a >= 0 && b >= 0 {
+                    let mut steps = Vec::new();
+
+                    if subtracting {
+                        steps.push(format!("Subtracting a negative is adding: {a} + {b}"));
+                    }
+
+                    steps.extend(sa_worked_addition(a, b));
+                    steps
+                } else if a < 0 && b < 0 {
+                    let mut steps = vec![format!(
+                        "Both parts are negative, so add {0} + {1} and keep the minus sign",
+                        -a, -b
+                    )];
+                    steps.extend(sa_worked_addition(-a, -b));
+                    steps
+                } else {
+                    let (big, small) = if a.abs() >= b.abs() { (a, b) } else { (b, a) };
+                    let mut steps = vec![format!(
+                        "Take {0} - {1}, the answer is {2}",
+                        big.abs(),
+                        small.abs(),
+                        if big < 0 { "negative" } else { "positive" }
+                    )];
+                    steps.extend(sa_worked_subtraction(big.abs(), small.abs()));
+                    steps
+                }
+            }
+            SAOperation::Multiplication => {
+                let mut steps = Vec::new();
+
+                if a < 0 || b < 0 {
+                    steps.push(format!(
+                        "Multiply {0} x {1}, the answer is {2}",
+                        a.abs(),
+                        b.abs(),
+                        if (a < 0) != (b < 0) {
+                            "negative"
+                        } else {
+                            "positive"
+                        }
+                    ));
+                }
+
+                steps.extend(sa_worked_multiplication(a.abs(), b.abs()));
+                steps
+            }
+            SAOperation::Division => {
+                if b == 0 {
+                    return vec!["Division by zero is undefined".to_owned()];
+                }
+
+                let mut steps = Vec::new();
+
+                if a < 0 || b < 0 {
+                    steps.push(format!(
+                        "Divide {0} / {1}, the answer is {2}",
+                        a.abs(),
+                        b.abs(),
+                        if (a < 0) != (b < 0) {
+                            "negative"
+                        } else {
+                            "positive"
+                        }
+                    ));
+                }
+
+                steps.extend(sa_worked_division(a.abs(), b.abs()));
+                steps
+            }
+        }
+    }
+}
+
+///sa_worked_section() - the optional 'Worked Solutions' section of the instructor copy.
+pub fn sa_worked_section<P: SARender>(data: &[P]) -> String {
+    let mut output = "\n---===---===---===---===---===\nWorked Solutions\n\n".to_owned();
+
+    for problem in data {
+        let steps = problem.worked_steps();
+
+        if steps.is_empty() {
+            continue;
+        }
+
+        output.push_str(&problem.instructor_string());
+
+        for step in steps {
+            output.push_str(&format!("    {step}\n"));
+        }
+
+        output.push('\n');
+    }
+
+    output
+}